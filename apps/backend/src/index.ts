@@ -4,7 +4,6 @@ import { cors } from "hono/cors";
 import { logger } from "./lib/logger";
 import chat from "./routes/chat";
 import googleCalendar from "./routes/google-calendar";
-import jira from "./routes/jira";
 
 const app = new Hono();
 
@@ -31,8 +30,6 @@ app.get("/healthz", (c) => {
 app.route("/chat", chat);
 // Google Calendar integration routes
 app.route("/", googleCalendar);
-// Jira integration routes
-app.route("/", jira);
 
 // Get port from environment or use default
 const port = Number(process.env.PORT) || 31337;
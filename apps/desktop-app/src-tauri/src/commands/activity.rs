@@ -0,0 +1,262 @@
+use super::claude::ClaudeSourceSettings;
+use super::github::{
+    parse_timestamp_minutes, validate_date, GitHubSourceSettings, LocalGitSourceSettings,
+};
+use super::jira::JiraSourceSettings;
+use futures_util::future::{join_all, BoxFuture};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashSet;
+use tauri::AppHandle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivitySourceKind {
+    Github,
+    LocalGit,
+    Claude,
+    Jira,
+}
+
+/// One entry of the merged daily timeline.
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ActivityItem {
+    pub source: ActivitySourceKind,
+    /// Source-specific kind, e.g. "pull_request" or "user".
+    pub kind: String,
+    pub title: String,
+    pub url: Option<String>,
+    pub timestamp: String,
+    /// Repository or project path the item belongs to.
+    pub project: Option<String>,
+    pub summary: Option<String>,
+    /// Set for commits.
+    pub sha: Option<String>,
+}
+
+/// Which sources to query, with the settings each one needs.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum ActivitySourceConfig {
+    Github(GitHubSourceSettings),
+    LocalGit(LocalGitSourceSettings),
+    Claude(ClaudeSourceSettings),
+    Jira(JiraSourceSettings),
+}
+
+impl ActivitySourceConfig {
+    fn into_source(self, app: &AppHandle) -> Result<Box<dyn ActivitySource>, String> {
+        match self {
+            Self::Github(settings) => settings.into_source(app),
+            Self::LocalGit(settings) => settings.into_source(app),
+            Self::Claude(settings) => settings.into_source(app),
+            Self::Jira(settings) => settings.into_source(app),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ActivitySourceStatus {
+    pub source: ActivitySourceKind,
+    pub item_count: u32,
+    /// Why this source failed. Items from the other sources are still returned.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct DailyActivityResponse {
+    pub date: String,
+    /// Items of all sources, newest first.
+    pub items: Vec<ActivityItem>,
+    pub sources: Vec<ActivitySourceStatus>,
+}
+
+/// A provider of activity items for the daily timeline.
+pub trait ActivitySource: Send + Sync {
+    fn kind(&self) -> ActivitySourceKind;
+
+    /// Activity on `date` (KST, "YYYY-MM-DD"), in any order.
+    fn daily_activity<'a>(
+        &'a self,
+        date: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<ActivityItem>, String>>;
+}
+
+/// Settings of one kind of source, implemented next to the source it
+/// builds. A new source needs only these and a variant of
+/// `ActivitySourceConfig`.
+pub trait ActivitySourceSettings {
+    fn into_source(self, app: &AppHandle) -> Result<Box<dyn ActivitySource>, String>;
+}
+
+/// Activity on `date` (KST) from all `sources`, queried concurrently and
+/// merged into one timeline. A failing source is reported in `sources`.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_daily_activity(
+    app: AppHandle,
+    date: String,
+    sources: Vec<ActivitySourceConfig>,
+) -> Result<DailyActivityResponse, String> {
+    let date = date.trim().to_string();
+    validate_date(&date)?;
+
+    let sources = sources
+        .into_iter()
+        .map(|config| config.into_source(&app))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let results = join_all(sources.iter().map(|source| source.daily_activity(&date))).await;
+
+    let mut items = Vec::new();
+    let mut statuses = Vec::new();
+
+    for (source, result) in sources.iter().zip(results) {
+        statuses.push(match result {
+            Ok(source_items) => {
+                let status = ActivitySourceStatus {
+                    source: source.kind(),
+                    item_count: source_items.len() as u32,
+                    error: None,
+                };
+                items.extend(source_items);
+                status
+            }
+            Err(error) => ActivitySourceStatus {
+                source: source.kind(),
+                item_count: 0,
+                error: Some(error),
+            },
+        });
+    }
+
+    dedup_commits(&mut items);
+    sort_newest_first(&mut items);
+
+    Ok(DailyActivityResponse {
+        date,
+        items,
+        sources: statuses,
+    })
+}
+
+/// Drop local commits that GitHub also reported, as those link to the commit.
+fn dedup_commits(items: &mut Vec<ActivityItem>) {
+    fn key(item: &ActivityItem) -> Option<(String, &str)> {
+        Some((item.project.as_ref()?.to_lowercase(), item.sha.as_deref()?))
+    }
+
+    let pushed: HashSet<(String, String)> = items
+        .iter()
+        .filter(|item| item.source == ActivitySourceKind::Github)
+        .filter_map(|item| key(item).map(|(repo, sha)| (repo, sha.to_string())))
+        .collect();
+    items.retain(|item| {
+        item.source != ActivitySourceKind::LocalGit
+            || !key(item).is_some_and(|(repo, sha)| pushed.contains(&(repo, sha.to_string())))
+    });
+}
+
+/// Sort by instant, as sources report timestamps in different offsets.
+fn sort_newest_first(items: &mut [ActivityItem]) {
+    items.sort_by(|a, b| {
+        parse_timestamp_minutes(&b.timestamp)
+            .cmp(&parse_timestamp_minutes(&a.timestamp))
+            .then_with(|| b.timestamp.cmp(&a.timestamp))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(timestamp: &str) -> ActivityItem {
+        ActivityItem {
+            source: ActivitySourceKind::Github,
+            kind: "commit".to_string(),
+            title: timestamp.to_string(),
+            url: None,
+            timestamp: timestamp.to_string(),
+            project: None,
+            summary: None,
+            sha: None,
+        }
+    }
+
+    fn commit(source: ActivitySourceKind, repo: &str, sha: &str) -> ActivityItem {
+        ActivityItem {
+            source,
+            project: Some(repo.to_string()),
+            sha: Some(sha.to_string()),
+            ..item("2026-01-22T10:00:00+09:00")
+        }
+    }
+
+    #[test]
+    fn drops_local_commits_also_on_github() {
+        let mut items = vec![
+            commit(ActivitySourceKind::LocalGit, "acme/api", "aaa"),
+            commit(ActivitySourceKind::LocalGit, "acme/api", "bbb"),
+            commit(ActivitySourceKind::LocalGit, "acme/web", "ccc"),
+            commit(ActivitySourceKind::Github, "Acme/API", "aaa"),
+            commit(ActivitySourceKind::Github, "acme/api", "ccc"),
+            item("2026-01-22T09:00:00+09:00"),
+        ];
+        dedup_commits(&mut items);
+        let kept: Vec<(ActivitySourceKind, Option<&str>)> = items
+            .iter()
+            .map(|item| (item.source, item.sha.as_deref()))
+            .collect();
+        assert_eq!(
+            kept,
+            [
+                (ActivitySourceKind::LocalGit, Some("bbb")),
+                (ActivitySourceKind::LocalGit, Some("ccc")),
+                (ActivitySourceKind::Github, Some("aaa")),
+                (ActivitySourceKind::Github, Some("ccc")),
+                (ActivitySourceKind::Github, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_source_configs() {
+        let configs: Vec<ActivitySourceConfig> = serde_json::from_str(
+            r#"[
+                {"source":"github"},
+                {"source":"local_git","repo_roots":["/src"],"author_emails":[]},
+                {"source":"claude","subscribed_folders":[]},
+                {"source":"jira"}
+            ]"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            configs.as_slice(),
+            [
+                ActivitySourceConfig::Github(GitHubSourceSettings { filter: None }),
+                ActivitySourceConfig::LocalGit(_),
+                ActivitySourceConfig::Claude(_),
+                ActivitySourceConfig::Jira(_),
+            ]
+        ));
+    }
+
+    #[test]
+    fn sorts_across_timestamp_offsets() {
+        let mut items = vec![
+            item("2026-01-22T10:00:00+09:00"),
+            item("2026-01-22T02:00:00Z"),
+            item("2026-01-22T00:30:00.120Z"),
+        ];
+        sort_newest_first(&mut items);
+        let order: Vec<&str> = items.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!(
+            order,
+            [
+                "2026-01-22T02:00:00Z",
+                "2026-01-22T10:00:00+09:00",
+                "2026-01-22T00:30:00.120Z",
+            ]
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use super::{
+    collect_subscribed_jsonl_files, extract_content, parse_date, parse_iso_timestamp, JsonlRecord,
+    SubscriptionMatcher,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaudeHistogramBucket {
+    Day,
+    Hour,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeHistogramEntry {
+    /// KST bucket key: "YYYY-MM-DD" for days, "YYYY-MM-DDTHH" for hours.
+    pub bucket: String,
+    pub project_path: String,
+    pub user_count: u32,
+    pub assistant_count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeActivityHistogram {
+    pub from: String,
+    pub to: String,
+    pub bucket: ClaudeHistogramBucket,
+    pub projects: Vec<String>,
+    /// One entry per bucket and project with activity, sorted by bucket.
+    pub entries: Vec<ClaudeHistogramEntry>,
+}
+
+/// (bucket, project) -> (user, assistant) message counts.
+type Counts = BTreeMap<(String, String), (u32, u32)>;
+
+/// KST date and bucket key of a timestamp, e.g. "2026-01-21T15:00:00Z" is
+/// ("2026-01-22", "2026-01-22T00") with hourly buckets.
+fn bucket_of(timestamp: &str, bucket: ClaudeHistogramBucket) -> Option<(String, String)> {
+    let (year, month, day, hour, _) = parse_iso_timestamp(timestamp)?;
+    let date_key = format!("{:04}-{:02}-{:02}", year, month, day);
+    let bucket_key = match bucket {
+        ClaudeHistogramBucket::Day => date_key.clone(),
+        ClaudeHistogramBucket::Hour => format!("{}T{:02}", date_key, hour),
+    };
+    Some((date_key, bucket_key))
+}
+
+/// Projects with activity, sorted, and one entry per bucket and project.
+fn histogram_entries(counts: Counts) -> (Vec<String>, Vec<ClaudeHistogramEntry>) {
+    let mut projects: Vec<String> = counts.keys().map(|(_, project)| project.clone()).collect();
+    projects.sort();
+    projects.dedup();
+
+    let entries = counts
+        .into_iter()
+        .map(
+            |((bucket, project_path), (user_count, assistant_count))| ClaudeHistogramEntry {
+                bucket,
+                project_path,
+                user_count,
+                assistant_count,
+            },
+        )
+        .collect();
+
+    (projects, entries)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_claude_activity_histogram(
+    subscribed_folders: Vec<String>,
+    from: String,
+    to: String,
+    bucket: ClaudeHistogramBucket,
+) -> Result<ClaudeActivityHistogram, String> {
+    let from = from.trim().to_string();
+    let to = to.trim().to_string();
+    parse_date(&from)?;
+    parse_date(&to)?;
+    if from > to {
+        return Err("Start date must not be after end date".to_string());
+    }
+
+    let subscriptions = SubscriptionMatcher::new(&subscribed_folders)?;
+
+    // Return empty if no folders subscribed
+    if subscriptions.is_empty() {
+        return Ok(ClaudeActivityHistogram {
+            from,
+            to,
+            bucket,
+            projects: Vec::new(),
+            entries: Vec::new(),
+        });
+    }
+
+    let mut counts = Counts::new();
+
+    for session_file in collect_subscribed_jsonl_files(&subscriptions)? {
+        let file = match fs::File::open(&session_file.path) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+        let reader = io::BufReader::new(file);
+
+        for line in reader.lines().map_while(Result::ok) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: JsonlRecord = match serde_json::from_str(&line) {
+                Ok(r) => r,
+                Err(_) => continue,
+            };
+
+            let is_user = match record.record_type.as_deref() {
+                Some("user") => true,
+                Some("assistant") => false,
+                _ => continue,
+            };
+
+            let (date_key, bucket_key) = match record
+                .timestamp
+                .as_deref()
+                .and_then(|timestamp| bucket_of(timestamp, bucket))
+            {
+                Some(keys) => keys,
+                None => continue,
+            };
+            if date_key < from || date_key > to {
+                continue;
+            }
+
+            let cwd = match &record.cwd {
+                Some(c) => c.as_str(),
+                None => continue,
+            };
+            if !subscriptions.matches(cwd) {
+                continue;
+            }
+
+            // Count only messages that would show up in the activity list
+            let has_content = record
+                .message
+                .as_ref()
+                .map(|message| !extract_content(message).is_empty())
+                .unwrap_or(false);
+            if !has_content {
+                continue;
+            }
+
+            let entry = counts.entry((bucket_key, cwd.to_string())).or_default();
+            if is_user {
+                entry.0 += 1;
+            } else {
+                entry.1 += 1;
+            }
+        }
+    }
+
+    let (projects, entries) = histogram_entries(counts);
+
+    Ok(ClaudeActivityHistogram {
+        from,
+        to,
+        bucket,
+        projects,
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_by_kst_day_and_hour() {
+        // 15:00 UTC is midnight KST
+        assert_eq!(
+            bucket_of("2026-01-21T14:59:59Z", ClaudeHistogramBucket::Day),
+            Some(("2026-01-21".to_string(), "2026-01-21".to_string()))
+        );
+        assert_eq!(
+            bucket_of("2026-01-21T15:00:00Z", ClaudeHistogramBucket::Day),
+            Some(("2026-01-22".to_string(), "2026-01-22".to_string()))
+        );
+        assert_eq!(
+            bucket_of("2026-01-21T14:59:59Z", ClaudeHistogramBucket::Hour),
+            Some(("2026-01-21".to_string(), "2026-01-21T23".to_string()))
+        );
+        assert_eq!(
+            bucket_of("2026-01-31T15:30:00.000Z", ClaudeHistogramBucket::Hour),
+            Some(("2026-02-01".to_string(), "2026-02-01T00".to_string()))
+        );
+        assert_eq!(bucket_of("yesterday", ClaudeHistogramBucket::Day), None);
+    }
+
+    #[test]
+    fn lists_projects_once_and_entries_by_bucket() {
+        let mut counts = Counts::new();
+        counts.insert(("2026-01-22".to_string(), "/work/web".to_string()), (1, 2));
+        counts.insert(("2026-01-21".to_string(), "/work/web".to_string()), (3, 0));
+        counts.insert(("2026-01-21".to_string(), "/work/api".to_string()), (0, 4));
+
+        let (projects, entries) = histogram_entries(counts);
+        assert_eq!(projects, ["/work/api", "/work/web"]);
+        let entries: Vec<(&str, &str, u32, u32)> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.bucket.as_str(),
+                    entry.project_path.as_str(),
+                    entry.user_count,
+                    entry.assistant_count,
+                )
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                ("2026-01-21", "/work/api", 0, 4),
+                ("2026-01-21", "/work/web", 3, 0),
+                ("2026-01-22", "/work/web", 1, 2),
+            ]
+        );
+    }
+}
@@ -0,0 +1,980 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+mod export;
+mod histogram;
+mod redaction;
+mod roots;
+mod search;
+mod session;
+mod source;
+mod subscription;
+mod watcher;
+
+use redaction::{redact, redaction_generation};
+use roots::claude_roots;
+use session::collapse_sidechains;
+use subscription::SubscriptionMatcher;
+
+pub use export::*;
+pub use histogram::*;
+pub use redaction::*;
+pub use roots::*;
+pub use search::*;
+pub use session::*;
+pub use source::ClaudeSourceSettings;
+pub use subscription::*;
+pub use watcher::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaudeActivityKind {
+    User,
+    Assistant,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ClaudeActivityItem {
+    pub kind: ClaudeActivityKind,
+    /// Message text with secrets redacted (see `redaction`).
+    pub content: String,
+    /// Number of secrets replaced in `content`.
+    pub redaction_count: u32,
+    pub timestamp: String,
+    pub project_path: String,
+    pub session_id: String,
+    /// Claude data directory the session was read from.
+    pub source_root: String,
+    pub uuid: Option<String>,
+    /// Record belongs to a sub-agent thread rather than the main conversation.
+    pub is_sidechain: bool,
+    /// Sub-agent items spawned by this prompt (see `collapse_sidechains`).
+    pub sidechain: Vec<ClaudeActivityItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeActivityResponse {
+    pub date: String,
+    pub items: Vec<ClaudeActivityItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeDateRange {
+    pub from: String,
+    pub to: String,
+}
+
+/// Price per million tokens for a model. `model` matches exactly or as a prefix
+/// of the model id recorded in the logs (e.g. "claude-sonnet-4").
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeModelPrice {
+    pub model: String,
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+    pub cache_creation_per_mtok: f64,
+    pub cache_read_per_mtok: f64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Type)]
+pub struct ClaudeTokenUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_creation_input_tokens: u64,
+    pub cache_read_input_tokens: u64,
+    /// Cost of the tokens whose model has a price. When `unpriced_tokens` is
+    /// not zero this covers only part of the usage.
+    pub estimated_cost: Option<f64>,
+    /// Tokens of models without a price, left out of `estimated_cost`.
+    pub unpriced_tokens: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeUsageBucket {
+    pub key: String,
+    pub usage: ClaudeTokenUsage,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeUsageResponse {
+    pub from: String,
+    pub to: String,
+    pub total: ClaudeTokenUsage,
+    pub by_day: Vec<ClaudeUsageBucket>,
+    pub by_project: Vec<ClaudeUsageBucket>,
+    pub by_model: Vec<ClaudeUsageBucket>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonlRecord {
+    #[serde(rename = "type")]
+    record_type: Option<String>,
+    timestamp: Option<String>,
+    message: Option<MessageContent>,
+    cwd: Option<String>,
+    #[serde(rename = "sessionId")]
+    session_id: Option<String>,
+    #[serde(rename = "requestId")]
+    request_id: Option<String>,
+    uuid: Option<String>,
+    #[serde(rename = "parentUuid")]
+    parent_uuid: Option<String>,
+    #[serde(rename = "isSidechain")]
+    is_sidechain: Option<bool>,
+    /// Title of a `summary` record
+    summary: Option<String>,
+    #[serde(rename = "leafUuid")]
+    leaf_uuid: Option<String>,
+    #[serde(rename = "gitBranch")]
+    git_branch: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MessageContent {
+    Simple(String),
+    Complex(ComplexMessage),
+}
+
+#[derive(Debug, Deserialize)]
+struct ComplexMessage {
+    id: Option<String>,
+    model: Option<String>,
+    content: Option<MessageBody>,
+    usage: Option<MessageUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageUsage {
+    input_tokens: Option<u64>,
+    output_tokens: Option<u64>,
+    cache_creation_input_tokens: Option<u64>,
+    cache_read_input_tokens: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MessageBody {
+    Text(String),
+    Array(Vec<ContentBlock>),
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentBlock {
+    #[serde(rename = "type")]
+    block_type: Option<String>,
+    text: Option<String>,
+    /// Tool name of a `tool_use` block
+    name: Option<String>,
+    /// Arguments of a `tool_use` block
+    input: Option<serde_json::Value>,
+    /// Output of a `tool_result` block (string or list of blocks)
+    content: Option<serde_json::Value>,
+    is_error: Option<bool>,
+}
+
+static PROJECT_PATH_CACHE: OnceLock<Mutex<HashMap<PathBuf, String>>> = OnceLock::new();
+
+fn get_cached_project_path(project_dir: &PathBuf) -> Option<String> {
+    let cache = PROJECT_PATH_CACHE.get()?;
+    let cache = cache.lock().ok()?;
+    cache.get(project_dir).cloned()
+}
+
+fn set_cached_project_path(project_dir: &PathBuf, value: String) {
+    let cache = PROJECT_PATH_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(project_dir.clone(), value);
+    }
+}
+
+/// A JSONL session file together with the Claude data directory it lives in.
+struct SessionFile {
+    root: String,
+    path: PathBuf,
+}
+
+/// `projects` directories of all configured Claude roots, paired with the root.
+fn projects_dirs() -> Result<Vec<(String, PathBuf)>, String> {
+    Ok(claude_roots()?
+        .into_iter()
+        .map(|root| {
+            let projects_dir = root.join("projects");
+            (root.to_string_lossy().to_string(), projects_dir)
+        })
+        .filter(|(_, projects_dir)| projects_dir.exists())
+        .collect())
+}
+
+/// Extract actual project path from JSONL files in the project directory.
+/// The folder name encoding is ambiguous (both '/' and '.' become '-'),
+/// so we read the `cwd` field from the first JSONL record instead.
+fn extract_project_path_from_dir(project_dir: &PathBuf) -> Option<String> {
+    if let Some(cached) = get_cached_project_path(project_dir) {
+        return Some(cached);
+    }
+
+    let jsonl_entries = fs::read_dir(project_dir).ok()?;
+
+    for entry in jsonl_entries.flatten() {
+        let path = entry.path();
+        if !path.extension().map(|e| e == "jsonl").unwrap_or(false) {
+            continue;
+        }
+
+        let file = fs::File::open(&path).ok()?;
+        let reader = io::BufReader::new(file);
+        for line in reader.lines().flatten() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Ok(record) = serde_json::from_str::<JsonlRecord>(&line) {
+                if let Some(cwd) = record.cwd {
+                    set_cached_project_path(project_dir, cwd.clone());
+                    return Some(cwd);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn parse_date(date: &str) -> Result<(i32, u32, u32), String> {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return Err("Date must be in YYYY-MM-DD format".to_string());
+    }
+
+    let year = parse_four_digits(&bytes[0..4]).ok_or("Invalid year")? as i32;
+    let month = parse_two_digits(&bytes[5..7]).ok_or("Invalid month")?;
+    let day = parse_two_digits(&bytes[8..10]).ok_or("Invalid day")?;
+
+    Ok((year, month, day))
+}
+
+/// Parse ISO 8601 timestamp and extract date components in KST timezone.
+/// Format: "2026-01-22T05:08:51.202Z"
+fn parse_iso_timestamp(timestamp: &str) -> Option<(i32, u32, u32, u32, u32)> {
+    let bytes = timestamp.as_bytes();
+    if bytes.len() < 16
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || bytes[10] != b'T'
+        || bytes[13] != b':'
+    {
+        return None;
+    }
+
+    let year = parse_four_digits(&bytes[0..4])? as i32;
+    let month = parse_two_digits(&bytes[5..7])?;
+    let day = parse_two_digits(&bytes[8..10])?;
+    let hour = parse_two_digits(&bytes[11..13])?;
+    let minute = parse_two_digits(&bytes[14..16])?;
+
+    // Convert UTC to KST (UTC+9)
+    let kst_hour = hour + 9;
+    if kst_hour >= 24 {
+        // Day overflow - move to next day
+        let (new_year, new_month, new_day) = add_one_day(year, month, day);
+        Some((new_year, new_month, new_day, kst_hour - 24, minute))
+    } else {
+        Some((year, month, day, kst_hour, minute))
+    }
+}
+
+fn parse_two_digits(bytes: &[u8]) -> Option<u32> {
+    if bytes.len() != 2 || !bytes[0].is_ascii_digit() || !bytes[1].is_ascii_digit() {
+        return None;
+    }
+    let tens = (bytes[0] - b'0') as u32;
+    let ones = (bytes[1] - b'0') as u32;
+    Some(tens * 10 + ones)
+}
+
+fn parse_four_digits(bytes: &[u8]) -> Option<u32> {
+    if bytes.len() != 4
+        || !bytes[0].is_ascii_digit()
+        || !bytes[1].is_ascii_digit()
+        || !bytes[2].is_ascii_digit()
+        || !bytes[3].is_ascii_digit()
+    {
+        return None;
+    }
+    let thousands = (bytes[0] - b'0') as u32;
+    let hundreds = (bytes[1] - b'0') as u32;
+    let tens = (bytes[2] - b'0') as u32;
+    let ones = (bytes[3] - b'0') as u32;
+    Some(thousands * 1000 + hundreds * 100 + tens * 10 + ones)
+}
+
+fn add_one_day(year: i32, month: u32, day: u32) -> (i32, u32, u32) {
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => 31,
+    };
+
+    if day < days_in_month {
+        (year, month, day + 1)
+    } else if month < 12 {
+        (year, month + 1, 1)
+    } else {
+        (year + 1, 1, 1)
+    }
+}
+
+fn timestamp_matches_date(timestamp: &str, year: i32, month: u32, day: u32) -> bool {
+    if let Some((y, m, d, _, _)) = parse_iso_timestamp(timestamp) {
+        y == year && m == month && d == day
+    } else {
+        false
+    }
+}
+
+/// Format the KST date of an ISO 8601 timestamp as "YYYY-MM-DD".
+fn kst_date_key(timestamp: &str) -> Option<String> {
+    let (year, month, day, _, _) = parse_iso_timestamp(timestamp)?;
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+fn timestamp_in_month(timestamp: &str, year: i32, month: u32) -> Option<u32> {
+    if let Some((y, m, d, _, _)) = parse_iso_timestamp(timestamp) {
+        if y == year && m == month {
+            return Some(d);
+        }
+    }
+    None
+}
+
+fn extract_content(message: &MessageContent) -> String {
+    match message {
+        MessageContent::Simple(s) => s.clone(),
+        MessageContent::Complex(c) => match &c.content {
+            Some(MessageBody::Text(t)) => t.clone(),
+            Some(MessageBody::Array(blocks)) => {
+                blocks
+                    .iter()
+                    .filter_map(|block| {
+                        if block.block_type.as_deref() == Some("text") {
+                            block.text.clone()
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            None => String::new(),
+        },
+    }
+}
+
+fn should_include_record(record_type: &str) -> bool {
+    matches!(record_type, "user" | "assistant")
+}
+
+fn subagent_jsonl_files(session_dir: &Path) -> Vec<PathBuf> {
+    let entries = match fs::read_dir(session_dir.join("subagents")) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|e| e == "jsonl").unwrap_or(false))
+        .collect()
+}
+
+/// Find the log files of one session (including its sub-agent logs) across all
+/// Claude roots, without applying folder subscriptions.
+fn find_session_files(session_id: &str) -> Result<Vec<SessionFile>, String> {
+    if session_id.is_empty()
+        || !session_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Invalid session id".to_string());
+    }
+
+    let mut files = Vec::new();
+
+    for (root, projects_dir) in projects_dirs()? {
+        let entries = fs::read_dir(&projects_dir)
+            .map_err(|e| format!("Failed to read projects directory: {}", e))?;
+
+        for entry in entries.flatten() {
+            let project_dir = entry.path();
+            let session_path = project_dir.join(format!("{}.jsonl", session_id));
+            if session_path.is_file() {
+                files.push(SessionFile {
+                    root: root.clone(),
+                    path: session_path,
+                });
+            }
+            files.extend(
+                subagent_jsonl_files(&project_dir.join(session_id))
+                    .into_iter()
+                    .map(|path| SessionFile {
+                        root: root.clone(),
+                        path,
+                    }),
+            );
+        }
+    }
+
+    Ok(files)
+}
+
+/// Collect JSONL session files from project directories whose project path
+/// matches one of the subscribed folders, across all Claude roots.
+fn collect_subscribed_jsonl_files(
+    subscriptions: &SubscriptionMatcher,
+) -> Result<Vec<SessionFile>, String> {
+    let mut files = Vec::new();
+
+    for (root, projects_dir) in projects_dirs()? {
+        let entries = fs::read_dir(&projects_dir)
+            .map_err(|e| format!("Failed to read projects directory: {}", e))?;
+
+        for entry in entries.flatten() {
+            if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                continue;
+            }
+
+            let project_dir = entry.path();
+
+            // Early filter: skip projects that don't match subscribed folders
+            match extract_project_path_from_dir(&project_dir) {
+                Some(project_path) if subscriptions.matches(&project_path) => {}
+                _ => continue,
+            }
+
+            if let Ok(jsonl_entries) = fs::read_dir(&project_dir) {
+                for jsonl_entry in jsonl_entries.flatten() {
+                    let path = jsonl_entry.path();
+                    if path.extension().map(|e| e == "jsonl").unwrap_or(false) {
+                        files.push(SessionFile {
+                            root: root.clone(),
+                            path,
+                        });
+                    } else if path.is_dir() {
+                        // Sub-agent logs live in `<session>/subagents/*.jsonl`
+                        files.extend(subagent_jsonl_files(&path).into_iter().map(|path| {
+                            SessionFile {
+                                root: root.clone(),
+                                path,
+                            }
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Convert a user/assistant record into an activity item, skipping records
+/// without text content or outside the subscribed folders.
+fn record_to_activity_item(
+    record: JsonlRecord,
+    source_root: &str,
+    subscriptions: &SubscriptionMatcher,
+) -> Option<ClaudeActivityItem> {
+    let kind = match record.record_type.as_deref()? {
+        "user" => ClaudeActivityKind::User,
+        "assistant" => ClaudeActivityKind::Assistant,
+        _ => return None,
+    };
+
+    let timestamp = record.timestamp?;
+
+    // Get cwd from record - skip if not present
+    let cwd = record.cwd?;
+
+    // Check if project is in subscribed folders
+    if !subscriptions.matches(&cwd) {
+        return None;
+    }
+
+    let content = extract_content(record.message.as_ref()?);
+
+    if content.is_empty() {
+        return None;
+    }
+
+    let (content, redaction_count) = redact(&content);
+
+    Some(ClaudeActivityItem {
+        kind,
+        content,
+        redaction_count,
+        timestamp,
+        project_path: cwd,
+        session_id: record.session_id.unwrap_or_default(),
+        source_root: source_root.to_string(),
+        uuid: record.uuid,
+        is_sidechain: record.is_sidechain.unwrap_or(false),
+        sidechain: Vec::new(),
+    })
+}
+
+fn read_jsonl_activities(
+    session_file: &SessionFile,
+    date: &str,
+    subscriptions: &SubscriptionMatcher,
+) -> Result<Vec<ClaudeActivityItem>, String> {
+    let (year, month, day) = parse_date(date)?;
+    let file =
+        fs::File::open(&session_file.path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let reader = io::BufReader::new(file);
+
+    let mut items = Vec::new();
+
+    for line in reader.lines() {
+        let line = line.map_err(|e| format!("Failed to read file: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record: JsonlRecord = match serde_json::from_str(&line) {
+            Ok(r) => r,
+            Err(_) => continue, // Skip invalid lines
+        };
+
+        let matches_date = record
+            .timestamp
+            .as_deref()
+            .map(|timestamp| timestamp_matches_date(timestamp, year, month, day))
+            .unwrap_or(false);
+
+        if !matches_date {
+            continue;
+        }
+
+        if let Some(item) = record_to_activity_item(record, &session_file.root, subscriptions) {
+            items.push(item);
+        }
+    }
+
+    Ok(items)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_claude_projects() -> Result<Vec<String>, String> {
+    let mut projects = HashSet::new();
+
+    // Merge projects across all Claude roots
+    for (_, projects_dir) in projects_dirs()? {
+        let entries = fs::read_dir(&projects_dir)
+            .map_err(|e| format!("Failed to read projects directory: {}", e))?;
+
+        for entry in entries.flatten() {
+            if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                let project_dir = entry.path();
+                if let Some(path) = extract_project_path_from_dir(&project_dir) {
+                    projects.insert(path);
+                }
+            }
+        }
+    }
+
+    let mut projects: Vec<String> = projects.into_iter().collect();
+    projects.sort();
+    Ok(projects)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_claude_activities(
+    date: String,
+    subscribed_folders: Vec<String>,
+) -> Result<ClaudeActivityResponse, String> {
+    let date = date.trim().to_string();
+    parse_date(&date)?; // Validate date format
+
+    let subscriptions = SubscriptionMatcher::new(&subscribed_folders)?;
+
+    // Return empty if no folders subscribed
+    if subscriptions.is_empty() {
+        return Ok(ClaudeActivityResponse {
+            date,
+            items: Vec::new(),
+        });
+    }
+
+    let mut all_items = Vec::new();
+
+    for session_file in collect_subscribed_jsonl_files(&subscriptions)? {
+        if let Ok(items) = read_jsonl_activities(&session_file, &date, &subscriptions) {
+            all_items.extend(items);
+        }
+    }
+
+    // Nest sub-agent chatter under the prompt that spawned it, then sort by
+    // timestamp descending (newest first)
+    let mut all_items = collapse_sidechains(all_items);
+    all_items.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    Ok(ClaudeActivityResponse {
+        date,
+        items: all_items,
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_claude_activity_dates(
+    subscribed_folders: Vec<String>,
+    year: i32,
+    month: u32,
+) -> Result<Vec<u32>, String> {
+    if month < 1 || month > 12 {
+        return Err("Month must be between 1 and 12".to_string());
+    }
+
+    let subscriptions = SubscriptionMatcher::new(&subscribed_folders)?;
+
+    // Return empty if no folders subscribed
+    if subscriptions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut days_with_activity: HashSet<u32> = HashSet::new();
+
+    for session_file in collect_subscribed_jsonl_files(&subscriptions)? {
+        let file = match fs::File::open(&session_file.path) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+        let reader = io::BufReader::new(file);
+
+        for line in reader.lines().flatten() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: JsonlRecord = match serde_json::from_str(&line) {
+                Ok(r) => r,
+                Err(_) => continue,
+            };
+
+            let record_type = match &record.record_type {
+                Some(t) => t.as_str(),
+                None => continue,
+            };
+
+            if !should_include_record(record_type) {
+                continue;
+            }
+
+            // Filter by subscribed folders using cwd field
+            let cwd = match &record.cwd {
+                Some(c) => c.as_str(),
+                None => continue,
+            };
+            if !subscriptions.matches(cwd) {
+                continue;
+            }
+
+            if let Some(ref timestamp) = record.timestamp {
+                if let Some(day) = timestamp_in_month(timestamp, year, month) {
+                    days_with_activity.insert(day);
+                }
+            }
+        }
+    }
+
+    let mut days: Vec<u32> = days_with_activity.into_iter().collect();
+    days.sort();
+    Ok(days)
+}
+
+impl ClaudeTokenUsage {
+    fn tokens(&self) -> u64 {
+        self.input_tokens
+            + self.output_tokens
+            + self.cache_creation_input_tokens
+            + self.cache_read_input_tokens
+    }
+
+    fn add(&mut self, other: &ClaudeTokenUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_creation_input_tokens += other.cache_creation_input_tokens;
+        self.cache_read_input_tokens += other.cache_read_input_tokens;
+        self.unpriced_tokens += other.unpriced_tokens;
+        if let Some(cost) = other.estimated_cost {
+            self.estimated_cost = Some(self.estimated_cost.unwrap_or(0.0) + cost);
+        }
+    }
+}
+
+/// Token usage of assistant messages, in total and per day, project and model.
+#[derive(Default)]
+struct UsageTally {
+    total: ClaudeTokenUsage,
+    by_day: HashMap<String, ClaudeTokenUsage>,
+    by_project: HashMap<String, ClaudeTokenUsage>,
+    by_model: HashMap<String, ClaudeTokenUsage>,
+    /// Assistant messages are logged once per content block with the same
+    /// usage, so each (message id, request id) pair is counted only once.
+    seen_messages: HashSet<(String, String)>,
+}
+
+impl UsageTally {
+    fn add(
+        &mut self,
+        record: &JsonlRecord,
+        day: String,
+        project: &str,
+        prices: &[ClaudeModelPrice],
+    ) {
+        let message = match &record.message {
+            Some(MessageContent::Complex(message)) => message,
+            _ => return,
+        };
+        let usage = match &message.usage {
+            Some(usage) => usage,
+            None => return,
+        };
+
+        if let Some(message_id) = &message.id {
+            let key = (
+                message_id.clone(),
+                record.request_id.clone().unwrap_or_default(),
+            );
+            if !self.seen_messages.insert(key) {
+                return;
+            }
+        }
+
+        let model = message
+            .model
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let mut item = ClaudeTokenUsage {
+            input_tokens: usage.input_tokens.unwrap_or(0),
+            output_tokens: usage.output_tokens.unwrap_or(0),
+            cache_creation_input_tokens: usage.cache_creation_input_tokens.unwrap_or(0),
+            cache_read_input_tokens: usage.cache_read_input_tokens.unwrap_or(0),
+            estimated_cost: None,
+            unpriced_tokens: 0,
+        };
+        match find_model_price(prices, &model) {
+            Some(price) => item.estimated_cost = Some(estimate_cost(&item, price)),
+            None => item.unpriced_tokens = item.tokens(),
+        }
+
+        self.total.add(&item);
+        self.by_day.entry(day).or_default().add(&item);
+        self.by_project
+            .entry(project.to_string())
+            .or_default()
+            .add(&item);
+        self.by_model.entry(model).or_default().add(&item);
+    }
+}
+
+/// Find the price entry for a model, preferring the longest matching prefix.
+fn find_model_price<'a>(
+    prices: &'a [ClaudeModelPrice],
+    model: &str,
+) -> Option<&'a ClaudeModelPrice> {
+    prices
+        .iter()
+        .filter(|price| !price.model.is_empty() && model.starts_with(price.model.as_str()))
+        .max_by_key(|price| price.model.len())
+}
+
+fn estimate_cost(usage: &ClaudeTokenUsage, price: &ClaudeModelPrice) -> f64 {
+    (usage.input_tokens as f64 * price.input_per_mtok
+        + usage.output_tokens as f64 * price.output_per_mtok
+        + usage.cache_creation_input_tokens as f64 * price.cache_creation_per_mtok
+        + usage.cache_read_input_tokens as f64 * price.cache_read_per_mtok)
+        / 1_000_000.0
+}
+
+fn into_usage_buckets(map: HashMap<String, ClaudeTokenUsage>) -> Vec<ClaudeUsageBucket> {
+    let mut buckets: Vec<ClaudeUsageBucket> = map
+        .into_iter()
+        .map(|(key, usage)| ClaudeUsageBucket { key, usage })
+        .collect();
+    buckets.sort_by(|a, b| a.key.cmp(&b.key));
+    buckets
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_claude_usage(
+    date_range: ClaudeDateRange,
+    subscribed_folders: Vec<String>,
+    prices: Option<Vec<ClaudeModelPrice>>,
+) -> Result<ClaudeUsageResponse, String> {
+    let from = date_range.from.trim().to_string();
+    let to = date_range.to.trim().to_string();
+    parse_date(&from)?;
+    parse_date(&to)?;
+    if from > to {
+        return Err("Start date must not be after end date".to_string());
+    }
+
+    let prices = prices.unwrap_or_default();
+
+    let subscriptions = SubscriptionMatcher::new(&subscribed_folders)?;
+
+    // Return empty totals if no folders subscribed
+    if subscriptions.is_empty() {
+        return Ok(ClaudeUsageResponse {
+            from,
+            to,
+            total: ClaudeTokenUsage::default(),
+            by_day: Vec::new(),
+            by_project: Vec::new(),
+            by_model: Vec::new(),
+        });
+    }
+
+    let mut tally = UsageTally::default();
+
+    for session_file in collect_subscribed_jsonl_files(&subscriptions)? {
+        let file = match fs::File::open(&session_file.path) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+        let reader = io::BufReader::new(file);
+
+        for line in reader.lines().map_while(Result::ok) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: JsonlRecord = match serde_json::from_str(&line) {
+                Ok(r) => r,
+                Err(_) => continue,
+            };
+
+            if record.record_type.as_deref() != Some("assistant") {
+                continue;
+            }
+
+            let cwd = match &record.cwd {
+                Some(c) => c.as_str(),
+                None => continue,
+            };
+            if !subscriptions.matches(cwd) {
+                continue;
+            }
+
+            let day = match record.timestamp.as_deref().and_then(kst_date_key) {
+                Some(day) => day,
+                None => continue,
+            };
+            if day < from || day > to {
+                continue;
+            }
+
+            tally.add(&record, day, cwd, &prices);
+        }
+    }
+
+    Ok(ClaudeUsageResponse {
+        from,
+        to,
+        total: tally.total,
+        by_day: into_usage_buckets(tally.by_day),
+        by_project: into_usage_buckets(tally.by_project),
+        by_model: into_usage_buckets(tally.by_model),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(model: &str, input_per_mtok: f64) -> ClaudeModelPrice {
+        ClaudeModelPrice {
+            model: model.to_string(),
+            input_per_mtok,
+            output_per_mtok: 0.0,
+            cache_creation_per_mtok: 0.0,
+            cache_read_per_mtok: 0.0,
+        }
+    }
+
+    fn assistant(
+        message_id: &str,
+        request_id: &str,
+        model: &str,
+        input_tokens: u64,
+    ) -> JsonlRecord {
+        serde_json::from_value(serde_json::json!({
+            "type": "assistant",
+            "requestId": request_id,
+            "message": {
+                "id": message_id,
+                "model": model,
+                "usage": { "input_tokens": input_tokens },
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn prefers_longest_price_prefix() {
+        let prices = [
+            price("claude-sonnet-4", 3.0),
+            price("claude-sonnet-4-5", 5.0),
+            price("", 100.0),
+        ];
+        let found = find_model_price(&prices, "claude-sonnet-4-5-20250929").unwrap();
+        assert_eq!(found.model, "claude-sonnet-4-5");
+        assert_eq!(
+            find_model_price(&prices, "claude-sonnet-4-20250514")
+                .unwrap()
+                .model,
+            "claude-sonnet-4"
+        );
+        assert!(find_model_price(&prices, "claude-opus-4").is_none());
+    }
+
+    #[test]
+    fn counts_each_message_once_and_flags_unpriced_usage() {
+        let prices = [price("claude-sonnet-4", 1.0)];
+        let mut tally = UsageTally::default();
+        for record in [
+            assistant("msg-1", "req-1", "claude-sonnet-4-20250514", 1_000_000),
+            // Same message logged again for another content block
+            assistant("msg-1", "req-1", "claude-sonnet-4-20250514", 1_000_000),
+            assistant("msg-1", "req-2", "claude-sonnet-4-20250514", 1_000_000),
+            assistant("msg-2", "req-3", "claude-opus-4", 500),
+        ] {
+            tally.add(&record, "2025-01-15".to_string(), "/work/app", &prices);
+        }
+
+        assert_eq!(tally.total.input_tokens, 2_000_500);
+        assert_eq!(tally.total.estimated_cost, Some(2.0));
+        assert_eq!(tally.total.unpriced_tokens, 500);
+        assert_eq!(
+            tally.by_model["claude-sonnet-4-20250514"].unpriced_tokens,
+            0
+        );
+        assert_eq!(tally.by_model["claude-opus-4"].estimated_cost, None);
+    }
+}
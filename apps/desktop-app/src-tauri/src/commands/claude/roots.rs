@@ -0,0 +1,109 @@
+use crate::commands::settings::{load_setting, save_setting};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+const SETTING_KEY: &str = "claudeDataDirs";
+
+/// Claude data directories configured in settings. Empty means the default root.
+static CLAUDE_ROOTS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+pub(super) fn expand_home(path: &str) -> Option<PathBuf> {
+    if path == "~" {
+        return dirs::home_dir();
+    }
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+        None => Some(PathBuf::from(path)),
+    }
+}
+
+/// Default Claude data directory: `CLAUDE_CONFIG_DIR` if set, otherwise `~/.claude`.
+fn default_claude_dir() -> Result<PathBuf, String> {
+    if let Ok(dir) = std::env::var("CLAUDE_CONFIG_DIR") {
+        let dir = dir.trim();
+        if !dir.is_empty() {
+            if let Some(path) = expand_home(dir) {
+                return Ok(path);
+            }
+        }
+    }
+
+    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
+    Ok(home.join(".claude"))
+}
+
+/// All Claude data directories to read from, in configured order.
+pub(super) fn claude_roots() -> Result<Vec<PathBuf>, String> {
+    let configured = CLAUDE_ROOTS
+        .lock()
+        .map_err(|e| format!("Failed to lock Claude roots: {}", e))?
+        .clone();
+
+    if configured.is_empty() {
+        Ok(vec![default_claude_dir()?])
+    } else {
+        Ok(configured)
+    }
+}
+
+fn roots_to_strings(roots: &[PathBuf]) -> Vec<String> {
+    roots
+        .iter()
+        .map(|root| root.to_string_lossy().to_string())
+        .collect()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_claude_data_dirs() -> Result<Vec<String>, String> {
+    Ok(roots_to_strings(&claude_roots()?))
+}
+
+fn apply_claude_data_dirs(roots: Vec<String>) -> Result<Vec<PathBuf>, String> {
+    let mut resolved: Vec<PathBuf> = Vec::new();
+
+    for root in roots {
+        let root = root.trim();
+        if root.is_empty() {
+            continue;
+        }
+        let path = expand_home(root).ok_or("Cannot find home directory")?;
+        if !path.is_dir() {
+            return Err(format!("Claude data directory does not exist: {}", root));
+        }
+        if !resolved.contains(&path) {
+            resolved.push(path);
+        }
+    }
+
+    let mut configured = CLAUDE_ROOTS
+        .lock()
+        .map_err(|e| format!("Failed to lock Claude roots: {}", e))?;
+    *configured = resolved.clone();
+    Ok(resolved)
+}
+
+/// Restore the saved Claude data directories. Called once during app setup.
+pub(crate) fn load_claude_data_dirs(app: &AppHandle) {
+    let Some(roots) = load_setting::<Vec<String>>(app, SETTING_KEY) else {
+        return;
+    };
+    if let Err(error) = apply_claude_data_dirs(roots) {
+        eprintln!("Ignoring saved Claude data directories: {}", error);
+    }
+}
+
+/// Replace the configured Claude data directories and save them. Passing an
+/// empty list restores the default root. Returns the effective list of roots.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_claude_data_dirs(
+    app: AppHandle,
+    roots: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let resolved = apply_claude_data_dirs(roots)?;
+    save_setting(&app, SETTING_KEY, &roots_to_strings(&resolved))?;
+
+    Ok(roots_to_strings(&claude_roots()?))
+}
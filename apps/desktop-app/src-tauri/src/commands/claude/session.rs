@@ -0,0 +1,338 @@
+use super::{
+    collect_subscribed_jsonl_files, extract_content, redact, ClaudeActivityItem,
+    ClaudeActivityKind, JsonlRecord, SubscriptionMatcher,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead};
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeSessionNode {
+    pub uuid: String,
+    pub kind: ClaudeActivityKind,
+    /// Message text with secrets redacted (see `redaction`).
+    pub content: String,
+    /// Number of secrets replaced in `content`.
+    pub redaction_count: u32,
+    pub timestamp: String,
+    pub is_sidechain: bool,
+    pub children: Vec<ClaudeSessionNode>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeSession {
+    pub session_id: String,
+    pub project_path: String,
+    pub source_root: String,
+    /// Titles from `summary` records, oldest first.
+    pub summaries: Vec<String>,
+    /// Main-thread turns, each a user prompt with the replies of that turn as
+    /// children. Sub-agent (sidechain) threads are nested under the prompt
+    /// that spawned them.
+    pub roots: Vec<ClaudeSessionNode>,
+}
+
+/// Minimal view of a record used to resolve `parentUuid` chains, including
+/// records (tool calls, tool results) that carry no displayable text.
+struct ThreadRecord {
+    parent_uuid: Option<String>,
+    node: Option<ClaudeSessionNode>,
+}
+
+/// Latest main-thread prompt `(timestamp, uuid)` at or before `timestamp`.
+fn spawning_prompt<'a>(
+    prompts: &'a [(String, String)],
+    timestamp: &str,
+) -> Option<&'a (String, String)> {
+    prompts
+        .iter()
+        .rev()
+        .find(|(prompt_timestamp, _)| prompt_timestamp.as_str() <= timestamp)
+}
+
+fn is_prompt(record: &ThreadRecord) -> bool {
+    record
+        .node
+        .as_ref()
+        .map(|node| matches!(node.kind, ClaudeActivityKind::User))
+        .unwrap_or(false)
+}
+
+/// Walk up the `parentUuid` chain from `start` to the nearest user prompt,
+/// skipping tool calls and other records without text. Results are memoized
+/// in `owners` so long sessions are walked once.
+fn find_turn_owner(
+    start: Option<String>,
+    records: &HashMap<String, ThreadRecord>,
+    owners: &mut HashMap<String, Option<String>>,
+) -> Option<String> {
+    let mut visited = Vec::new();
+    let mut current = start;
+
+    let owner = loop {
+        let uuid = match current {
+            Some(uuid) => uuid,
+            None => break None,
+        };
+        if let Some(owner) = owners.get(&uuid) {
+            break owner.clone();
+        }
+        let record = match records.get(&uuid) {
+            Some(record) => record,
+            None => break None,
+        };
+        if is_prompt(record) {
+            break Some(uuid);
+        }
+        // Guard against malformed logs with parent cycles
+        if visited.len() > records.len() {
+            break None;
+        }
+        current = record.parent_uuid.clone();
+        visited.push(uuid);
+    };
+
+    for uuid in visited {
+        owners.insert(uuid, owner.clone());
+    }
+    owner
+}
+
+/// Nest sidechain (sub-agent) items under the main-thread user prompt of the
+/// same session that spawned them. Sidechain items whose prompt is not part of
+/// `items` stay at the top level.
+pub(super) fn collapse_sidechains(mut items: Vec<ClaudeActivityItem>) -> Vec<ClaudeActivityItem> {
+    items.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let mut collapsed: Vec<ClaudeActivityItem> = Vec::new();
+    // session id -> (timestamp, index in `collapsed`) of main-thread prompts
+    let mut prompts: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+
+    for item in items {
+        if item.is_sidechain {
+            let parent = prompts.get(&item.session_id).and_then(|session_prompts| {
+                session_prompts
+                    .iter()
+                    .rev()
+                    .find(|(timestamp, _)| *timestamp <= item.timestamp)
+                    .map(|(_, index)| *index)
+            });
+            if let Some(index) = parent {
+                collapsed[index].sidechain.push(item);
+                continue;
+            }
+        } else if matches!(item.kind, ClaudeActivityKind::User) {
+            prompts
+                .entry(item.session_id.clone())
+                .or_default()
+                .push((item.timestamp.clone(), collapsed.len()));
+        }
+        collapsed.push(item);
+    }
+
+    collapsed
+}
+
+fn attach_children(
+    uuid: &str,
+    children_of: &mut HashMap<String, Vec<String>>,
+    nodes: &mut HashMap<String, ClaudeSessionNode>,
+) -> Option<ClaudeSessionNode> {
+    let mut node = nodes.remove(uuid)?;
+    for child in children_of.remove(uuid).unwrap_or_default() {
+        if let Some(child) = attach_children(&child, children_of, nodes) {
+            node.children.push(child);
+        }
+    }
+    node.children.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    Some(node)
+}
+
+/// Displayable node for a user or assistant record with text, redacted.
+fn session_node(uuid: &str, record: &JsonlRecord) -> Option<ClaudeSessionNode> {
+    let kind = match record.record_type.as_deref() {
+        Some("user") => ClaudeActivityKind::User,
+        Some("assistant") => ClaudeActivityKind::Assistant,
+        _ => return None,
+    };
+
+    let content = extract_content(record.message.as_ref()?);
+    if content.is_empty() {
+        return None;
+    }
+    let (content, redaction_count) = redact(&content);
+
+    Some(ClaudeSessionNode {
+        uuid: uuid.to_string(),
+        kind,
+        content,
+        redaction_count,
+        timestamp: record.timestamp.clone()?,
+        is_sidechain: record.is_sidechain.unwrap_or(false),
+        children: Vec::new(),
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_claude_session(
+    session_id: String,
+    subscribed_folders: Vec<String>,
+) -> Result<ClaudeSession, String> {
+    let session_id = session_id.trim().to_string();
+    if session_id.is_empty() {
+        return Err("Session id is required".to_string());
+    }
+
+    let subscriptions = SubscriptionMatcher::new(&subscribed_folders)?;
+
+    let mut records: HashMap<String, ThreadRecord> = HashMap::new();
+    let mut summaries: Vec<(String, String)> = Vec::new();
+    let mut project_path = None;
+    let mut source_root = None;
+
+    for session_file in collect_subscribed_jsonl_files(&subscriptions)? {
+        let file = match fs::File::open(&session_file.path) {
+            Ok(file) => file,
+            Err(_) => continue,
+        };
+        let reader = io::BufReader::new(file);
+
+        for line in reader.lines().map_while(Result::ok) {
+            // Cheap pre-filter before parsing: other sessions' records are skipped
+            if !line.contains(session_id.as_str()) && !line.contains("\"summary\"") {
+                continue;
+            }
+
+            let record: JsonlRecord = match serde_json::from_str(&line) {
+                Ok(r) => r,
+                Err(_) => continue,
+            };
+
+            if record.record_type.as_deref() == Some("summary") {
+                // Summary records carry no session id; they point at a leaf message.
+                if let (Some(summary), Some(leaf_uuid)) = (record.summary, record.leaf_uuid) {
+                    summaries.push((leaf_uuid, summary));
+                }
+                continue;
+            }
+
+            if record.session_id.as_deref() != Some(session_id.as_str()) {
+                continue;
+            }
+
+            let uuid = match record.uuid.clone() {
+                Some(uuid) => uuid,
+                None => continue,
+            };
+
+            if project_path.is_none() {
+                if let Some(cwd) = &record.cwd {
+                    if subscriptions.matches(cwd) {
+                        project_path = Some(cwd.clone());
+                        source_root = Some(session_file.root.clone());
+                    }
+                }
+            }
+
+            let node = session_node(&uuid, &record);
+            records.insert(
+                uuid,
+                ThreadRecord {
+                    parent_uuid: record.parent_uuid,
+                    node,
+                },
+            );
+        }
+    }
+
+    let project_path = match project_path {
+        Some(path) => path,
+        None => return Err(format!("Session not found: {}", session_id)),
+    };
+
+    let summaries = summaries
+        .into_iter()
+        .filter(|(leaf_uuid, _)| records.contains_key(leaf_uuid))
+        .map(|(_, summary)| redact(&summary).0)
+        .collect();
+
+    // Main-thread prompts, used to place sidechain threads by start time.
+    let mut main_prompts: Vec<(String, String)> = records
+        .values()
+        .filter_map(|record| record.node.as_ref())
+        .filter(|node| !node.is_sidechain && matches!(node.kind, ClaudeActivityKind::User))
+        .map(|node| (node.timestamp.clone(), node.uuid.clone()))
+        .collect();
+    main_prompts.sort();
+
+    // Group each message under the prompt of its turn. Main-thread prompts are
+    // top-level; sidechain prompts hang under the main prompt that spawned them.
+    let mut owners: HashMap<String, Option<String>> = HashMap::new();
+    let mut children_of: HashMap<String, Vec<String>> = HashMap::new();
+    let mut top_level: Vec<String> = Vec::new();
+
+    for (uuid, record) in &records {
+        let node = match &record.node {
+            Some(node) => node,
+            None => continue,
+        };
+
+        let parent = if is_prompt(record) {
+            if node.is_sidechain {
+                spawning_prompt(&main_prompts, &node.timestamp)
+                    .map(|(_, prompt_uuid)| prompt_uuid.clone())
+            } else {
+                None
+            }
+        } else {
+            find_turn_owner(record.parent_uuid.clone(), &records, &mut owners)
+        };
+
+        match parent {
+            Some(parent_uuid) => children_of
+                .entry(parent_uuid)
+                .or_default()
+                .push(uuid.clone()),
+            None => top_level.push(uuid.clone()),
+        }
+    }
+
+    let mut nodes: HashMap<String, ClaudeSessionNode> = records
+        .into_iter()
+        .filter_map(|(uuid, record)| record.node.map(|node| (uuid, node)))
+        .collect();
+
+    let mut roots: Vec<ClaudeSessionNode> = top_level
+        .iter()
+        .filter_map(|uuid| attach_children(uuid, &mut children_of, &mut nodes))
+        .collect();
+    roots.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    Ok(ClaudeSession {
+        session_id,
+        project_path,
+        source_root: source_root.unwrap_or_default(),
+        summaries,
+        roots,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_content_masks_secrets() {
+        let record: JsonlRecord = serde_json::from_str(
+            r#"{"type":"assistant","timestamp":"2025-01-15T01:00:00Z","uuid":"u1","message":{"role":"assistant","content":[{"type":"text","text":"Use sk-ant-REDACTED for the call"}]}}"#,
+        )
+        .unwrap();
+
+        let node = session_node("u1", &record).expect("text record");
+        assert_eq!(node.content, "Use [REDACTED:anthropic_key] for the call");
+        assert_eq!(node.redaction_count, 1);
+    }
+}
@@ -0,0 +1,87 @@
+use super::{get_claude_activities, ClaudeActivityItem, ClaudeActivityKind};
+use crate::commands::activity::{
+    ActivityItem, ActivitySource, ActivitySourceKind, ActivitySourceSettings,
+};
+use futures_util::future::{BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::AppHandle;
+
+/// Characters of a message's first line kept as the title.
+const TITLE_CHARS: usize = 120;
+
+impl ClaudeActivityItem {
+    fn into_activity_item(self) -> ActivityItem {
+        let kind = match self.kind {
+            ClaudeActivityKind::User => "user",
+            ClaudeActivityKind::Assistant => "assistant",
+        };
+        let first_line = self
+            .content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or_default();
+        let title = match first_line.char_indices().nth(TITLE_CHARS) {
+            Some((index, _)) => format!("{}…", &first_line[..index]),
+            None => first_line.to_string(),
+        };
+        let summary = (!self.sidechain.is_empty())
+            .then(|| format!("{} sub-agent messages", self.sidechain.len()));
+
+        ActivityItem {
+            source: ActivitySourceKind::Claude,
+            kind: kind.to_string(),
+            title,
+            url: None,
+            timestamp: self.timestamp,
+            project: Some(self.project_path),
+            summary,
+            sha: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ClaudeSourceSettings {
+    pub subscribed_folders: Vec<String>,
+}
+
+impl ActivitySourceSettings for ClaudeSourceSettings {
+    fn into_source(self, _app: &AppHandle) -> Result<Box<dyn ActivitySource>, String> {
+        Ok(Box::new(ClaudeActivitySource::new(self.subscribed_folders)))
+    }
+}
+
+/// Prompts and replies in subscribed Claude projects.
+struct ClaudeActivitySource {
+    subscribed_folders: Vec<String>,
+}
+
+impl ClaudeActivitySource {
+    pub fn new(subscribed_folders: Vec<String>) -> Self {
+        Self { subscribed_folders }
+    }
+}
+
+impl ActivitySource for ClaudeActivitySource {
+    fn kind(&self) -> ActivitySourceKind {
+        ActivitySourceKind::Claude
+    }
+
+    fn daily_activity<'a>(
+        &'a self,
+        date: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<ActivityItem>, String>> {
+        async move {
+            let response =
+                get_claude_activities(date.to_string(), self.subscribed_folders.clone()).await?;
+            Ok(response
+                .items
+                .into_iter()
+                .map(ClaudeActivityItem::into_activity_item)
+                .collect())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,304 @@
+use super::roots::expand_home;
+use super::{extract_project_path_from_dir, projects_dirs};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct ClaudeSubscriptionPreview {
+    /// Projects selected by the pattern set.
+    pub matched: Vec<String>,
+    /// Projects matched by an include pattern but removed by an exclude pattern.
+    pub excluded: Vec<String>,
+}
+
+enum FolderPattern {
+    /// Plain folder: matches itself and everything below it.
+    Folder(Vec<PathBuf>),
+    /// Glob pattern: matches a path if the path or one of its ancestors matches.
+    Glob(Vec<Regex>),
+}
+
+/// Matches project paths against subscribed folders.
+///
+/// Entries are plain folders (`/work/app`) or globs (`~/work/**`); entries
+/// starting with `!` exclude matching paths (`!**/scratch`). Matching is done
+/// per path component after expanding `~`, dropping trailing slashes and
+/// resolving symlinks, so `/work/app` does not match `/work/app-legacy`.
+pub(super) struct SubscriptionMatcher {
+    includes: Vec<FolderPattern>,
+    excludes: Vec<FolderPattern>,
+}
+
+static CANONICAL_PATH_CACHE: OnceLock<Mutex<HashMap<PathBuf, Option<PathBuf>>>> = OnceLock::new();
+
+fn is_glob(value: &str) -> bool {
+    value.contains(['*', '?', '['])
+}
+
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+fn canonicalize_cached(path: &Path) -> Option<PathBuf> {
+    let cache = CANONICAL_PATH_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Ok(cache) = cache.lock() {
+        if let Some(cached) = cache.get(path) {
+            return cached.clone();
+        }
+    }
+
+    let canonical = fs::canonicalize(path).ok();
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(path.to_path_buf(), canonical.clone());
+    }
+    canonical
+}
+
+/// Lexically normalized path plus its symlink-resolved form when it differs.
+fn path_variants(path: &Path) -> Vec<PathBuf> {
+    let normalized = normalize_lexically(path);
+    let mut variants = vec![normalized.clone()];
+    if let Some(canonical) = canonicalize_cached(&normalized) {
+        if canonical != normalized {
+            variants.push(canonical);
+        }
+    }
+    variants
+}
+
+fn glob_to_regex(pattern: &str) -> Result<Regex, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut expression = String::from("^");
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                // `**/` matches zero or more whole components
+                if chars.get(i + 2) == Some(&'/') {
+                    expression.push_str("(?:[^/]*/)*");
+                    i += 3;
+                } else {
+                    expression.push_str(".*");
+                    i += 2;
+                }
+            }
+            '*' => {
+                expression.push_str("[^/]*");
+                i += 1;
+            }
+            '?' => {
+                expression.push_str("[^/]");
+                i += 1;
+            }
+            '[' => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|&c| c == ']')
+                    .ok_or_else(|| format!("Unclosed '[' in pattern: {}", pattern))?;
+                let class: String = chars[i + 1..i + end].iter().collect();
+                expression.push('[');
+                match class.strip_prefix('!') {
+                    Some(negated) => {
+                        expression.push('^');
+                        expression.push_str(negated);
+                    }
+                    None => expression.push_str(&class),
+                }
+                expression.push(']');
+                i += end + 1;
+            }
+            c => {
+                expression.push_str(&regex::escape(&c.to_string()));
+                i += 1;
+            }
+        }
+    }
+
+    expression.push('$');
+    Regex::new(&expression).map_err(|e| format!("Invalid pattern {}: {}", pattern, e))
+}
+
+fn parse_pattern(value: &str) -> Result<FolderPattern, String> {
+    let expanded = expand_home(value).ok_or("Cannot find home directory")?;
+
+    if !is_glob(value) {
+        return Ok(FolderPattern::Folder(path_variants(&expanded)));
+    }
+
+    let expanded = expanded.to_string_lossy().to_string();
+    let mut regexes = vec![glob_to_regex(&expanded)?];
+
+    // Resolve symlinks in the literal part of the pattern (before the first glob).
+    if expanded.starts_with('/') {
+        let literal: Vec<&str> = expanded
+            .split('/')
+            .take_while(|segment| !is_glob(segment))
+            .collect();
+        let literal_prefix = literal.join("/");
+        if !literal_prefix.is_empty() {
+            if let Some(canonical) = canonicalize_cached(Path::new(&literal_prefix)) {
+                let canonical = canonical.to_string_lossy().to_string();
+                if canonical != literal_prefix {
+                    let rest = &expanded[literal_prefix.len()..];
+                    regexes.push(glob_to_regex(&format!("{}{}", canonical, rest))?);
+                }
+            }
+        }
+    }
+
+    Ok(FolderPattern::Glob(regexes))
+}
+
+impl FolderPattern {
+    fn matches(&self, variants: &[PathBuf]) -> bool {
+        match self {
+            FolderPattern::Folder(folders) => variants
+                .iter()
+                .any(|path| folders.iter().any(|folder| path.starts_with(folder))),
+            FolderPattern::Glob(regexes) => variants.iter().any(|path| {
+                path.ancestors().any(|ancestor| {
+                    let ancestor = ancestor.to_string_lossy();
+                    regexes.iter().any(|regex| regex.is_match(&ancestor))
+                })
+            }),
+        }
+    }
+}
+
+impl SubscriptionMatcher {
+    pub(super) fn new(subscribed_folders: &[String]) -> Result<Self, String> {
+        let mut includes = Vec::new();
+        let mut excludes = Vec::new();
+
+        for entry in subscribed_folders {
+            let entry = entry.trim();
+            if let Some(exclude) = entry.strip_prefix('!') {
+                let exclude = exclude.trim();
+                if !exclude.is_empty() {
+                    excludes.push(parse_pattern(exclude)?);
+                }
+            } else if !entry.is_empty() {
+                includes.push(parse_pattern(entry)?);
+            }
+        }
+
+        Ok(Self { includes, excludes })
+    }
+
+    /// True when there are no include patterns, i.e. nothing is subscribed.
+    pub(super) fn is_empty(&self) -> bool {
+        self.includes.is_empty()
+    }
+
+    fn is_included(&self, variants: &[PathBuf]) -> bool {
+        self.includes
+            .iter()
+            .any(|pattern| pattern.matches(variants))
+    }
+
+    fn is_excluded(&self, variants: &[PathBuf]) -> bool {
+        self.excludes
+            .iter()
+            .any(|pattern| pattern.matches(variants))
+    }
+
+    pub(super) fn matches(&self, path: &str) -> bool {
+        let variants = path_variants(Path::new(path));
+        self.is_included(&variants) && !self.is_excluded(&variants)
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_subscription(
+    patterns: Vec<String>,
+) -> Result<ClaudeSubscriptionPreview, String> {
+    let matcher = SubscriptionMatcher::new(&patterns)?;
+
+    let mut matched = BTreeSet::new();
+    let mut excluded = BTreeSet::new();
+
+    for (_, projects_dir) in projects_dirs()? {
+        let entries = fs::read_dir(&projects_dir)
+            .map_err(|e| format!("Failed to read projects directory: {}", e))?;
+
+        for entry in entries.flatten() {
+            if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                continue;
+            }
+
+            let project_path = match extract_project_path_from_dir(&entry.path()) {
+                Some(path) => path,
+                None => continue,
+            };
+
+            let variants = path_variants(Path::new(&project_path));
+            if !matcher.is_included(&variants) {
+                continue;
+            }
+
+            if matcher.is_excluded(&variants) {
+                excluded.insert(project_path);
+            } else {
+                matched.insert(project_path);
+            }
+        }
+    }
+
+    Ok(ClaudeSubscriptionPreview {
+        matched: matched.into_iter().collect(),
+        excluded: excluded.into_iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(patterns: &[&str]) -> SubscriptionMatcher {
+        let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+        SubscriptionMatcher::new(&patterns).expect("valid patterns")
+    }
+
+    #[test]
+    fn folder_matches_by_component() {
+        let m = matcher(&["/work/app/"]);
+        assert!(m.matches("/work/app"));
+        assert!(m.matches("/work/app/src"));
+        assert!(!m.matches("/work/app-legacy"));
+    }
+
+    #[test]
+    fn glob_with_exclude() {
+        let m = matcher(&["/work/**", "!**/scratch"]);
+        assert!(m.matches("/work/app"));
+        assert!(m.matches("/work/team/app"));
+        assert!(!m.matches("/work/team/scratch"));
+        assert!(!m.matches("/work/scratch/notes"));
+        assert!(!m.matches("/other/app"));
+    }
+
+    #[test]
+    fn single_star_stays_within_component() {
+        let m = matcher(&["/work/*-api"]);
+        assert!(m.matches("/work/billing-api"));
+        assert!(m.matches("/work/billing-api/src"));
+        assert!(!m.matches("/work/billing/api"));
+    }
+}
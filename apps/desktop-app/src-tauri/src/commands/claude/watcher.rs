@@ -0,0 +1,181 @@
+use super::{
+    extract_project_path_from_dir, projects_dirs, record_to_activity_item, JsonlRecord,
+    SubscriptionMatcher,
+};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
+use walkdir::WalkDir;
+
+const ACTIVITY_APPENDED_EVENT: &str = "claude-activity-appended";
+
+/// Active watcher on the `projects` directory of every Claude root. Dropping
+/// the watcher stops it.
+static CLAUDE_WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+
+/// Byte offset up to which each JSONL file has already been read.
+type TailOffsets = Arc<Mutex<HashMap<PathBuf, u64>>>;
+
+fn is_jsonl(path: &Path) -> bool {
+    path.extension().map(|e| e == "jsonl").unwrap_or(false)
+}
+
+/// Project directory (first level under `projects`) that contains `path`.
+fn project_dir_for(path: &Path, projects_dir: &Path) -> Option<PathBuf> {
+    let relative = path.strip_prefix(projects_dir).ok()?;
+    let first = relative.components().next()?;
+    Some(projects_dir.join(first))
+}
+
+/// Read complete lines appended since the last recorded offset. A trailing
+/// partial line is left for the next modification event.
+fn read_appended_lines(path: &Path, offsets: &TailOffsets) -> Vec<String> {
+    let mut offsets = match offsets.lock() {
+        Ok(offsets) => offsets,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(_) => return Vec::new(),
+    };
+    let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+    // New files are read from the start; truncated files are re-read.
+    let mut offset = offsets.get(path).copied().unwrap_or(0);
+    if offset > len {
+        offset = 0;
+    }
+    if offset == len || file.seek(SeekFrom::Start(offset)).is_err() {
+        return Vec::new();
+    }
+
+    let mut buffer = Vec::new();
+    if file.read_to_end(&mut buffer).is_err() {
+        return Vec::new();
+    }
+
+    let complete = match buffer.iter().rposition(|&b| b == b'\n') {
+        Some(last_newline) => last_newline + 1,
+        None => return Vec::new(),
+    };
+    offsets.insert(path.to_path_buf(), offset + complete as u64);
+
+    String::from_utf8_lossy(&buffer[..complete])
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn handle_event(
+    app: &AppHandle,
+    event: notify::Event,
+    watched_dirs: &[(String, PathBuf)],
+    subscriptions: &SubscriptionMatcher,
+    offsets: &TailOffsets,
+) {
+    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+        return;
+    }
+
+    for path in event.paths.iter().filter(|path| is_jsonl(path)) {
+        let found = watched_dirs.iter().find_map(|(root, projects_dir)| {
+            project_dir_for(path, projects_dir).map(|dir| (root, dir))
+        });
+        let (root, project_dir) = match found {
+            Some(found) => found,
+            None => continue,
+        };
+
+        // Early filter: skip projects that don't match subscribed folders
+        match extract_project_path_from_dir(&project_dir) {
+            Some(project_path) if subscriptions.matches(&project_path) => {}
+            _ => continue,
+        }
+
+        for line in read_appended_lines(path, offsets) {
+            let record: JsonlRecord = match serde_json::from_str(&line) {
+                Ok(r) => r,
+                Err(_) => continue,
+            };
+
+            if let Some(item) = record_to_activity_item(record, root, subscriptions) {
+                let _ = app.emit(ACTIVITY_APPENDED_EVENT, item);
+            }
+        }
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn start_claude_activity_watch(
+    app: AppHandle,
+    subscribed_folders: Vec<String>,
+) -> Result<(), String> {
+    let subscriptions = SubscriptionMatcher::new(&subscribed_folders)?;
+
+    let watched_dirs = projects_dirs()?;
+    if watched_dirs.is_empty() {
+        return Err("Claude projects directory does not exist".to_string());
+    }
+
+    // Start tailing from the current end of every existing session file so
+    // only newly appended records are emitted.
+    let initial_offsets: HashMap<PathBuf, u64> = watched_dirs
+        .iter()
+        .flat_map(|(_, projects_dir)| WalkDir::new(projects_dir).into_iter())
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file() && is_jsonl(e.path()))
+        .filter_map(|e| {
+            let len = e.metadata().ok()?.len();
+            Some((e.path().to_path_buf(), len))
+        })
+        .collect();
+    let offsets: TailOffsets = Arc::new(Mutex::new(initial_offsets));
+
+    let handler_dirs = watched_dirs.clone();
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        if let Ok(event) = result {
+            handle_event(&app, event, &handler_dirs, &subscriptions, &offsets);
+        }
+    })
+    .map_err(|e| format!("Failed to create Claude activity watcher: {}", e))?;
+
+    for (_, projects_dir) in &watched_dirs {
+        watcher
+            .watch(projects_dir, RecursiveMode::Recursive)
+            .map_err(|e| format!("Failed to watch Claude projects directory: {}", e))?;
+    }
+
+    // Replacing an existing watcher drops (and stops) the previous one.
+    let mut watcher_guard = CLAUDE_WATCHER
+        .lock()
+        .map_err(|e| format!("Failed to lock watcher: {}", e))?;
+    *watcher_guard = Some(watcher);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_claude_activity_watch() -> Result<(), String> {
+    let mut watcher_guard = CLAUDE_WATCHER
+        .lock()
+        .map_err(|e| format!("Failed to lock watcher: {}", e))?;
+    *watcher_guard = None;
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn is_claude_activity_watch_active() -> bool {
+    CLAUDE_WATCHER
+        .lock()
+        .map(|watcher| watcher.is_some())
+        .unwrap_or(false)
+}
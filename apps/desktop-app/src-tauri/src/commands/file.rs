@@ -14,7 +14,7 @@ pub struct FileEntry {
 
 /// Validates that the given path is within the vault directory.
 /// Returns the canonicalized path if valid, or an error if the path is outside the vault.
-fn validate_path_within_vault(path: &str, vault_path: &str) -> Result<PathBuf, String> {
+pub(crate) fn validate_path_within_vault(path: &str, vault_path: &str) -> Result<PathBuf, String> {
     let target_path = Path::new(path);
     let vault = Path::new(vault_path);
 
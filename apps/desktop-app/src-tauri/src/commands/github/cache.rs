@@ -0,0 +1,112 @@
+use super::hosts::{normalize_host, DEFAULT_GITHUB_HOST};
+use super::GitHubActivityItem;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tauri::{AppHandle, Manager};
+
+/// Logins looked up from each host's token, kept for the session.
+static LOGIN_CACHE: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+/// Bumped when cached days lack activity that is fetched now.
+const CACHE_VERSION: u32 = 4;
+
+/// Activity of one host on one day, as stored on disk.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedHostActivity {
+    /// Missing in files written before versioning.
+    #[serde(default)]
+    pub version: u32,
+    pub login: String,
+    pub items: Vec<GitHubActivityItem>,
+    /// Why some activity may be missing, e.g. the day is older than the
+    /// events window.
+    #[serde(default)]
+    pub incomplete: Option<String>,
+}
+
+impl CachedHostActivity {
+    pub fn new(login: String, items: Vec<GitHubActivityItem>, incomplete: Option<String>) -> Self {
+        Self {
+            version: CACHE_VERSION,
+            login,
+            items,
+            incomplete,
+        }
+    }
+}
+
+pub(super) fn cached_login(host: &str) -> Option<String> {
+    let cache = LOGIN_CACHE.get()?;
+    let cache = cache.lock().ok()?;
+    cache.get(host).cloned()
+}
+
+pub(super) fn cache_login(host: &str, login: &str) {
+    let cache = LOGIN_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(host.to_string(), login.to_string());
+    }
+}
+
+/// Drop the cached login of `host` (github.com when `None`), e.g. after its
+/// token changed.
+pub(crate) fn forget_github_login(host: Option<&str>) {
+    let host = match host {
+        Some(host) => match normalize_host(host) {
+            Ok(host) => host,
+            Err(_) => return,
+        },
+        None => DEFAULT_GITHUB_HOST.to_string(),
+    };
+    if let Some(cache) = LOGIN_CACHE.get() {
+        if let Ok(mut cache) = cache.lock() {
+            cache.remove(&host);
+        }
+    }
+}
+
+pub(super) fn activity_cache_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| format!("Failed to get app cache dir: {}", e))?;
+    Ok(cache_dir.join("github-activity"))
+}
+
+fn cache_file(cache_dir: &Path, host: &str, date: &str) -> PathBuf {
+    // Host names may carry a port, which is not a valid file name character everywhere
+    cache_dir
+        .join(host.replace(':', "_"))
+        .join(format!("{}.json", date))
+}
+
+pub(super) fn read_cached_activity(
+    cache_dir: &Path,
+    host: &str,
+    date: &str,
+) -> Option<CachedHostActivity> {
+    let content = fs::read_to_string(cache_file(cache_dir, host, date)).ok()?;
+    let activity: CachedHostActivity = serde_json::from_str(&content).ok()?;
+    (activity.version == CACHE_VERSION).then_some(activity)
+}
+
+/// Best effort: a failed write only means the day is fetched again next time.
+pub(super) fn write_cached_activity(
+    cache_dir: &Path,
+    host: &str,
+    date: &str,
+    activity: &CachedHostActivity,
+) {
+    let path = cache_file(cache_dir, host, date);
+    if let Some(parent) = path.parent() {
+        if fs::create_dir_all(parent).is_err() {
+            return;
+        }
+    }
+    if let Ok(content) = serde_json::to_string(activity) {
+        let _ = fs::write(path, content);
+    }
+}
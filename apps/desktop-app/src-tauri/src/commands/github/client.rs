@@ -0,0 +1,513 @@
+use super::hosts::GitHubHost;
+use crate::commands::secure::read_github_token;
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, ETAG, IF_NONE_MATCH, LINK, RETRY_AFTER,
+    USER_AGENT,
+};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::ErrorKind;
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const GITHUB_USER_AGENT: &str = "en-ttokk";
+const GITHUB_JSON: &str = "application/vnd.github+json";
+/// Pages followed through `Link: rel="next"`. Search results stop at 1000
+/// items anyway, i.e. 10 pages of 100.
+const MAX_PAGES: usize = 10;
+/// Retries after a secondary rate limit before giving up.
+const MAX_RETRIES: u32 = 3;
+/// Longest wait honoured between retries; longer limits are reported instead.
+const MAX_RETRY_WAIT_SECS: u64 = 60;
+/// Responses kept for ETag revalidation before the cache is reset.
+const MAX_CACHED_RESPONSES: usize = 256;
+
+/// Last response with an ETag per request URL, for conditional requests.
+static RESPONSE_CACHE: OnceLock<Mutex<HashMap<String, CachedResponse>>> = OnceLock::new();
+
+#[derive(Clone)]
+struct CachedResponse {
+    etag: String,
+    body: String,
+    headers: HeaderMap,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GitHubError {
+    /// Primary or secondary rate limit. `reset_at` is a Unix timestamp in
+    /// seconds when known.
+    RateLimited {
+        message: String,
+        reset_at: Option<u64>,
+        secondary: bool,
+    },
+    Request {
+        message: String,
+    },
+}
+
+impl fmt::Display for GitHubError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitHubError::RateLimited { message, .. } | GitHubError::Request { message } => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl From<String> for GitHubError {
+    fn from(message: String) -> Self {
+        GitHubError::Request { message }
+    }
+}
+
+impl From<&str> for GitHubError {
+    fn from(message: &str) -> Self {
+        GitHubError::Request {
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiError {
+    message: Option<String>,
+}
+
+/// Response body that holds one page of a paginated list.
+pub(super) trait Page<T> {
+    fn into_items(self) -> Vec<T>;
+}
+
+impl<T> Page<T> for Vec<T> {
+    fn into_items(self) -> Vec<T> {
+        self
+    }
+}
+
+/// Authenticated client for the GitHub REST API.
+pub(super) struct GitHubClient {
+    http: reqwest::Client,
+    host: String,
+    base_url: String,
+    token: String,
+}
+
+impl GitHubClient {
+    /// Build a client for `host`, using the token stored in the keyring or,
+    /// failing that, the one `gh` is logged in with for that host.
+    pub(super) async fn for_host(host: &GitHubHost) -> Result<Self, GitHubError> {
+        let token = resolve_token(&host.host).await?;
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            host: host.host.clone(),
+            base_url: host.api_base_url(),
+            token,
+        })
+    }
+
+    pub(super) fn host(&self) -> &str {
+        &self.host
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+
+    /// Send one GET request, retrying with backoff on secondary rate limits.
+    /// Responses carrying an ETag are remembered and revalidated with
+    /// `If-None-Match`, so unchanged results cost a 304 instead of a full fetch.
+    /// Returns the body and headers of a successful response.
+    async fn send(
+        &self,
+        url: &str,
+        query: &[(&str, &str)],
+        accept: Option<&str>,
+    ) -> Result<(String, HeaderMap), GitHubError> {
+        let mut attempt = 0;
+
+        loop {
+            let mut request = self
+                .http
+                .get(url)
+                .query(query)
+                .header(USER_AGENT, GITHUB_USER_AGENT)
+                .header(ACCEPT, accept.unwrap_or(GITHUB_JSON))
+                .header(AUTHORIZATION, format!("Bearer {}", self.token))
+                .header("X-GitHub-Api-Version", "2022-11-28")
+                .build()
+                .map_err(|e| format!("Invalid GitHub request: {}", e))?;
+
+            let cache_key = request.url().to_string();
+            let cached = cached_response(&cache_key);
+            if let Some(cached) = &cached {
+                if let Ok(etag) = HeaderValue::from_str(&cached.etag) {
+                    request.headers_mut().insert(IF_NONE_MATCH, etag);
+                }
+            }
+
+            let response = self
+                .http
+                .execute(request)
+                .await
+                .map_err(|e| format!("GitHub request failed: {}", e))?;
+
+            let status = response.status();
+            if status == StatusCode::NOT_MODIFIED {
+                if let Some(cached) = cached {
+                    return Ok((cached.body, cached.headers));
+                }
+            }
+
+            let headers = response.headers().clone();
+            let body = response
+                .text()
+                .await
+                .map_err(|e| format!("Failed to read GitHub response: {}", e))?;
+
+            if status.is_success() {
+                if let Some(etag) = headers.get(ETAG).and_then(|etag| etag.to_str().ok()) {
+                    cache_response(
+                        cache_key,
+                        CachedResponse {
+                            etag: etag.to_string(),
+                            body: body.clone(),
+                            headers: headers.clone(),
+                        },
+                    );
+                }
+                return Ok((body, headers));
+            }
+
+            let error = api_error(status, &headers, &body);
+            if let GitHubError::RateLimited {
+                secondary: true, ..
+            } = &error
+            {
+                let wait = retry_wait(&headers, attempt);
+                if attempt < MAX_RETRIES && wait <= MAX_RETRY_WAIT_SECS {
+                    attempt += 1;
+                    tokio::time::sleep(Duration::from_secs(wait)).await;
+                    continue;
+                }
+            }
+            return Err(error);
+        }
+    }
+
+    /// GET `path` (relative to the base URL) and decode the JSON body.
+    pub(super) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        accept: Option<&str>,
+    ) -> Result<T, GitHubError> {
+        let (body, _) = self.send(&self.url(path), query, accept).await?;
+        decode(&body)
+    }
+
+    /// GET `path` and follow `Link: rel="next"` for up to `MAX_PAGES` pages,
+    /// collecting the items of every page.
+    pub(super) async fn get_pages<P, T>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        accept: Option<&str>,
+    ) -> Result<Vec<T>, GitHubError>
+    where
+        P: DeserializeOwned + Page<T>,
+    {
+        let mut items = Vec::new();
+        let (mut body, mut headers) = self.send(&self.url(path), query, accept).await?;
+
+        for page in 1.. {
+            let decoded: P = decode(&body)?;
+            items.extend(decoded.into_items());
+
+            let next = match next_page_url(&headers) {
+                Some(next) if page < MAX_PAGES => next,
+                _ => break,
+            };
+            // The next link already carries the query string
+            (body, headers) = self.send(&next, &[], accept).await?;
+        }
+
+        Ok(items)
+    }
+}
+
+fn cached_response(key: &str) -> Option<CachedResponse> {
+    let cache = RESPONSE_CACHE.get()?;
+    let cache = cache.lock().ok()?;
+    cache.get(key).cloned()
+}
+
+fn cache_response(key: String, response: CachedResponse) {
+    let cache = RESPONSE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Ok(mut cache) = cache.lock() {
+        if cache.len() >= MAX_CACHED_RESPONSES && !cache.contains_key(&key) {
+            cache.clear();
+        }
+        cache.insert(key, response);
+    }
+}
+
+fn decode<T: DeserializeOwned>(body: &str) -> Result<T, GitHubError> {
+    serde_json::from_str(body).map_err(|e| format!("Invalid GitHub response: {}", e).into())
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Seconds to wait before retrying: `Retry-After` when given, otherwise the
+/// rate-limit reset time, otherwise exponential backoff (5s, 10s, 20s).
+fn retry_wait(headers: &HeaderMap, attempt: u32) -> u64 {
+    if let Some(seconds) = headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+    {
+        return seconds;
+    }
+    if let Some(reset) = header_u64(headers, "x-ratelimit-reset") {
+        if header_u64(headers, "x-ratelimit-remaining") == Some(0) {
+            return reset.saturating_sub(now_secs()).max(1);
+        }
+    }
+    5 * 2u64.pow(attempt)
+}
+
+/// URL of the `rel="next"` entry in a `Link` header.
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|entry| {
+        let (url, params) = entry.split_once(';')?;
+        let is_next = params
+            .split(';')
+            .any(|param| param.trim() == "rel=\"next\"");
+        is_next.then(|| {
+            url.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
+}
+
+fn api_error(status: StatusCode, headers: &HeaderMap, body: &str) -> GitHubError {
+    let message = serde_json::from_str::<ApiError>(body)
+        .ok()
+        .and_then(|error| error.message)
+        .unwrap_or_else(|| body.trim().to_string());
+
+    let exhausted = header_u64(headers, "x-ratelimit-remaining") == Some(0);
+    let secondary = message.to_lowercase().contains("secondary rate limit")
+        || (headers.contains_key(RETRY_AFTER) && !exhausted);
+
+    if matches!(
+        status,
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+    ) && (exhausted || secondary)
+    {
+        let reset_at = header_u64(headers, "x-ratelimit-reset").or_else(|| {
+            header_u64(headers, RETRY_AFTER.as_str()).map(|seconds| now_secs() + seconds)
+        });
+        let wait = reset_at
+            .map(|reset| {
+                let minutes = reset.saturating_sub(now_secs()).div_ceil(60).max(1);
+                format!(" Try again in {} min.", minutes)
+            })
+            .unwrap_or_default();
+        let kind = if secondary {
+            "secondary rate limit"
+        } else {
+            "rate limit"
+        };
+        return GitHubError::RateLimited {
+            message: format!("GitHub {} exceeded.{}", kind, wait),
+            reset_at,
+            secondary,
+        };
+    }
+
+    match status {
+        StatusCode::UNAUTHORIZED => format!(
+            "GitHub token is not authenticated ({}). Update the token in settings or run `gh auth login`.",
+            message
+        )
+        .into(),
+        _ if message.is_empty() => format!("GitHub request failed ({})", status).into(),
+        _ => format!("GitHub request failed ({}): {}", status, message).into(),
+    }
+}
+
+async fn resolve_token(host: &str) -> Result<String, GitHubError> {
+    if let Some(token) = read_github_token(Some(host))?.filter(|token| !token.trim().is_empty()) {
+        return Ok(token.trim().to_string());
+    }
+
+    let missing = format!(
+        "GitHub token not available for {}. Add a token in settings or run `gh auth login`.",
+        host
+    );
+
+    let gh_host = host.to_string();
+    let token = tauri::async_runtime::spawn_blocking(move || {
+        run_gh(&["auth", "token", "--hostname", &gh_host])
+    })
+    .await
+    .map_err(|error| format!("GitHub auth task failed: {}", error))?
+    .map_err(|error| format!("{} ({})", missing, error))?;
+
+    let token = token.trim().to_string();
+    if token.is_empty() {
+        return Err(missing.into());
+    }
+    Ok(token)
+}
+
+fn run_gh(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("gh")
+        .env("GH_PROMPT_DISABLED", "1")
+        .env("GH_NO_UPDATE_NOTIFIER", "1")
+        .args(args)
+        .output()
+        .map_err(|error| match error.kind() {
+            ErrorKind::NotFound => {
+                "GitHub CLI (gh) not found. Install from https://cli.github.com/".to_string()
+            }
+            _ => format!("Failed to run gh: {}", error),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if stderr.is_empty() {
+            "GitHub CLI request failed".to_string()
+        } else {
+            stderr
+        });
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|error| format!("GitHub CLI returned invalid UTF-8: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::test_support::serve;
+
+    fn client(base_url: &str) -> GitHubClient {
+        GitHubClient {
+            http: reqwest::Client::new(),
+            host: "github.com".to_string(),
+            base_url: base_url.to_string(),
+            token: "secret".to_string(),
+        }
+    }
+
+    #[test]
+    fn follows_link_pagination() {
+        let (base_url, server) = serve(&[
+            (
+                "200 OK",
+                "Link: <{base}/items?per_page=2&page=2>; rel=\"next\", <{base}/items?per_page=2&page=2>; rel=\"last\"\r\n",
+                "[1,2]",
+            ),
+            ("200 OK", "", "[3]"),
+        ]);
+        let client = client(&base_url);
+
+        let items: Vec<u32> = tauri::async_runtime::block_on(client.get_pages::<Vec<u32>, u32>(
+            "items",
+            &[("per_page", "2")],
+            None,
+        ))
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /items?per_page=2 "));
+        assert!(requests[1].starts_with("GET /items?per_page=2&page=2 "));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains("authorization: bearer secret"));
+    }
+
+    #[test]
+    fn revalidates_with_etag() {
+        let (base_url, server) = serve(&[
+            ("200 OK", "ETag: \"v1\"\r\n", r#"{"login":"kim"}"#),
+            ("304 Not Modified", "ETag: \"v1\"\r\n", ""),
+        ]);
+        let client = client(&base_url);
+
+        for _ in 0..2 {
+            let user: serde_json::Value =
+                tauri::async_runtime::block_on(client.get("user", &[], None)).unwrap();
+            assert_eq!(user["login"], "kim");
+        }
+
+        let requests = server.join().unwrap();
+        assert!(!requests[0].to_lowercase().contains("if-none-match"));
+        assert!(requests[1].to_lowercase().contains("if-none-match: \"v1\""));
+    }
+
+    #[test]
+    fn retries_secondary_rate_limit() {
+        let (base_url, server) = serve(&[
+            (
+                "403 Forbidden",
+                "Retry-After: 0\r\n",
+                r#"{"message":"You have exceeded a secondary rate limit."}"#,
+            ),
+            ("200 OK", "", "[1]"),
+        ]);
+        let client = client(&base_url);
+
+        let items: Vec<u32> =
+            tauri::async_runtime::block_on(client.get_pages::<Vec<u32>, u32>("items", &[], None))
+                .unwrap();
+        assert_eq!(items, vec![1]);
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn reports_primary_rate_limit_without_retrying() {
+        let (base_url, server) = serve(&[(
+            "403 Forbidden",
+            "x-ratelimit-remaining: 0\r\nx-ratelimit-reset: 4102444800\r\n",
+            r#"{"message":"API rate limit exceeded"}"#,
+        )]);
+        let client = client(&base_url);
+
+        let error =
+            tauri::async_runtime::block_on(client.get_pages::<Vec<u32>, u32>("items", &[], None))
+                .unwrap_err();
+        assert!(matches!(
+            error,
+            GitHubError::RateLimited {
+                secondary: false,
+                reset_at: Some(4102444800),
+                ..
+            }
+        ));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+}
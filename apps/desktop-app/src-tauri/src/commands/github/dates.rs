@@ -0,0 +1,184 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// KST is UTC+9 with no daylight saving time.
+const KST_OFFSET_MINUTES: i64 = 9 * 60;
+
+/// Days since 1970-01-01 of a (year, month, day) date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn parse_number(value: &str) -> Option<i64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Days since 1970-01-01 of a "YYYY-MM-DD" date.
+fn parse_date(date: &str) -> Option<i64> {
+    let mut parts = date.splitn(3, '-');
+    let year = parse_number(parts.next()?)?;
+    let month = parse_number(parts.next()?)? as u32;
+    let day = parse_number(parts.next()?)? as u32;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// Minutes since the epoch (UTC) of an ISO 8601 timestamp such as
+/// "2026-01-22T05:08:51Z" or "2026-01-22T14:08:51+09:00".
+pub(crate) fn parse_timestamp_minutes(timestamp: &str) -> Option<i64> {
+    if timestamp.len() < 16 || timestamp.as_bytes()[10] != b'T' {
+        return None;
+    }
+    let days = parse_date(&timestamp[..10])?;
+    let hour = parse_number(timestamp.get(11..13)?)?;
+    let minute = parse_number(timestamp.get(14..16)?)?;
+
+    // Offset follows the seconds and optional fraction: "Z", "+09:00" or "-05:00"
+    let rest = timestamp.get(16..)?;
+    let offset = match rest.find(['Z', '+', '-']) {
+        Some(index) if rest[index..].starts_with('Z') => 0,
+        Some(index) => {
+            let sign = if rest[index..].starts_with('-') {
+                -1
+            } else {
+                1
+            };
+            let offset = rest.get(index + 1..)?;
+            let hours = parse_number(offset.get(..2)?)?;
+            let minutes = parse_number(offset.get(3..5).unwrap_or("00"))?;
+            sign * (hours * 60 + minutes)
+        }
+        None => 0,
+    };
+
+    Some(days * 1440 + hour * 60 + minute - offset)
+}
+
+fn now_minutes() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64 / 60)
+        .unwrap_or_default()
+}
+
+/// Today's date in KST as "YYYY-MM-DD".
+pub(super) fn kst_today() -> String {
+    format_date((now_minutes() + KST_OFFSET_MINUTES).div_euclid(1440))
+}
+
+/// Whole hours elapsed since an ISO 8601 timestamp, zero for future ones.
+pub(super) fn hours_since(timestamp: &str) -> Option<u32> {
+    let minutes = now_minutes() - parse_timestamp_minutes(timestamp)?;
+    Some((minutes.max(0) / 60) as u32)
+}
+
+/// KST date ("YYYY-MM-DD") of an ISO 8601 timestamp.
+pub(crate) fn kst_date_of(timestamp: &str) -> Option<String> {
+    let minutes = parse_timestamp_minutes(timestamp)?;
+    Some(format_date((minutes + KST_OFFSET_MINUTES).div_euclid(1440)))
+}
+
+/// Number of days in the inclusive range `from..=to`; zero or negative when
+/// `to` is before `from`.
+pub(super) fn day_span(from: &str, to: &str) -> Option<i64> {
+    Some(parse_date(to)? - parse_date(from)? + 1)
+}
+
+/// `date` moved by `days` days, e.g. "2026-01-31" plus one is "2026-02-01".
+pub(crate) fn add_days(date: &str, days: i64) -> Option<String> {
+    Some(format_date(parse_date(date)? + days))
+}
+
+/// Start of a KST day as a UTC timestamp, e.g. "2026-01-22" becomes
+/// "2026-01-21T15:00:00Z".
+pub(super) fn kst_day_start_utc(date: &str) -> Option<String> {
+    let minutes = parse_date(date)? * 1440 - KST_OFFSET_MINUTES;
+    let time = minutes.rem_euclid(1440);
+    Some(format!(
+        "{}T{:02}:{:02}:00Z",
+        format_date(minutes.div_euclid(1440)),
+        time / 60,
+        time % 60
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_utc_timestamps_to_kst_dates() {
+        assert_eq!(
+            kst_date_of("2026-01-22T05:08:51Z").as_deref(),
+            Some("2026-01-22")
+        );
+        assert_eq!(
+            kst_date_of("2026-01-22T15:00:00.120Z").as_deref(),
+            Some("2026-01-23")
+        );
+        assert_eq!(
+            kst_date_of("2025-12-31T16:30:00Z").as_deref(),
+            Some("2026-01-01")
+        );
+    }
+
+    #[test]
+    fn honours_timestamp_offsets() {
+        assert_eq!(
+            kst_date_of("2026-01-22T23:30:00+09:00").as_deref(),
+            Some("2026-01-22")
+        );
+        assert_eq!(
+            kst_date_of("2026-01-22T10:30:00-05:00").as_deref(),
+            Some("2026-01-23")
+        );
+    }
+
+    #[test]
+    fn adds_days_across_months() {
+        assert_eq!(add_days("2026-01-31", 1).as_deref(), Some("2026-02-01"));
+        assert_eq!(add_days("2024-02-28", 1).as_deref(), Some("2024-02-29"));
+        assert_eq!(add_days("2026-01-01", -1).as_deref(), Some("2025-12-31"));
+    }
+
+    #[test]
+    fn day_start_in_utc() {
+        assert_eq!(
+            kst_day_start_utc("2026-01-22").as_deref(),
+            Some("2026-01-21T15:00:00Z")
+        );
+        assert_eq!(
+            kst_day_start_utc("2024-03-01").as_deref(),
+            Some("2024-02-29T15:00:00Z")
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Search qualifiers added at most, keeping queries under GitHub's length limit.
+const MAX_QUALIFIERS: usize = 6;
+
+/// Repository filter. Patterns with a `/` match "owner/repo", the others
+/// match the owner (user or org). `*` and `?` are wildcards; matching ignores case.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct GitHubRepoFilter {
+    /// Keep only repositories matching one of these. Empty keeps all.
+    pub include: Vec<String>,
+    /// Drop repositories matching any of these, even if included.
+    pub exclude: Vec<String>,
+}
+
+/// A validated pattern.
+struct RepoPattern {
+    source: String,
+    regex: Regex,
+    is_repo: bool,
+}
+
+impl RepoPattern {
+    fn new(pattern: &str) -> Result<Self, String> {
+        let source = pattern.trim().to_lowercase();
+        let valid = !source.is_empty()
+            && source.matches('/').count() <= 1
+            && source.chars().all(|c| {
+                c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | '*' | '?')
+            });
+        if !valid {
+            return Err(format!("Invalid repository pattern: {}", pattern));
+        }
+
+        let mut regex = String::from("^");
+        for c in source.chars() {
+            match c {
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                _ => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        regex.push('$');
+
+        Ok(Self {
+            is_repo: source.contains('/'),
+            regex: Regex::new(&regex).map_err(|e| e.to_string())?,
+            source,
+        })
+    }
+
+    fn matches(&self, repo: &str) -> bool {
+        let repo = repo.to_lowercase();
+        if self.is_repo {
+            self.regex.is_match(&repo)
+        } else {
+            let owner = repo.split('/').next().unwrap_or_default();
+            self.regex.is_match(owner)
+        }
+    }
+
+    fn is_literal(&self) -> bool {
+        !self.source.contains(['*', '?'])
+    }
+
+    /// `org:`/`repo:` qualifier, or `None` when the pattern needs post-filtering.
+    /// `org:` also matches user accounts in search.
+    fn qualifier(&self) -> Option<String> {
+        if !self.is_literal() {
+            return None;
+        }
+        let name = if self.is_repo { "repo" } else { "org" };
+        Some(format!("{}:{}", name, self.source))
+    }
+}
+
+pub(super) struct RepoFilter {
+    include: Vec<RepoPattern>,
+    exclude: Vec<RepoPattern>,
+}
+
+impl RepoFilter {
+    pub fn new(filter: &GitHubRepoFilter) -> Result<Self, String> {
+        let parse = |patterns: &[String]| {
+            patterns
+                .iter()
+                .filter(|pattern| !pattern.trim().is_empty())
+                .map(|pattern| RepoPattern::new(pattern))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            include: parse(&filter.include)?,
+            exclude: parse(&filter.exclude)?,
+        })
+    }
+
+    pub fn matches(&self, repo: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|pattern| pattern.matches(repo)))
+            && !self.exclude.iter().any(|pattern| pattern.matches(repo))
+    }
+
+    /// Search qualifiers narrowing results to the filter, with a leading space.
+    /// Includes only narrow the search when all of them are literal, as
+    /// repeated qualifiers are OR-ed; wildcards are left to `matches`.
+    pub fn qualifiers(&self) -> String {
+        let mut qualifiers = Vec::new();
+
+        let includes: Option<Vec<String>> =
+            self.include.iter().map(RepoPattern::qualifier).collect();
+        if let Some(includes) = includes {
+            qualifiers.extend(includes);
+        }
+        qualifiers.extend(
+            self.exclude
+                .iter()
+                .filter_map(RepoPattern::qualifier)
+                .map(|qualifier| format!("-{}", qualifier)),
+        );
+
+        if qualifiers.len() > MAX_QUALIFIERS {
+            return String::new();
+        }
+        qualifiers
+            .iter()
+            .map(|qualifier| format!(" {}", qualifier))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> RepoFilter {
+        RepoFilter::new(&GitHubRepoFilter {
+            include: include.iter().map(|s| s.to_string()).collect(),
+            exclude: exclude.iter().map(|s| s.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn matches_org_and_repo_globs() {
+        let filter = filter(&["acme", "*/notes"], &["acme/legacy-*"]);
+        assert!(filter.matches("Acme/api"));
+        assert!(filter.matches("someone/notes"));
+        assert!(!filter.matches("acme/legacy-web"));
+        assert!(!filter.matches("someone/dotfiles"));
+    }
+
+    #[test]
+    fn builds_qualifiers_for_literal_patterns() {
+        assert_eq!(
+            filter(&["acme", "other/repo"], &["acme/sandbox"]).qualifiers(),
+            " org:acme repo:other/repo -repo:acme/sandbox"
+        );
+        assert_eq!(
+            filter(&["acme-*"], &["acme-x"]).qualifiers(),
+            " -org:acme-x"
+        );
+    }
+}
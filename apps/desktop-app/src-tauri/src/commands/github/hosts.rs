@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+use crate::commands::settings::{load_setting, save_setting};
+
+pub(crate) const DEFAULT_GITHUB_HOST: &str = "github.com";
+const SETTING_KEY: &str = "githubHosts";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GitHubHost {
+    /// Host name, e.g. "github.com" or "github.example.com".
+    pub host: String,
+    /// REST API base URL. Defaults to https://api.github.com for github.com and
+    /// `https://<host>/api/v3` for GitHub Enterprise Server.
+    pub api_url: Option<String>,
+    /// Login to report activity for. Looked up from the token when empty.
+    pub login: Option<String>,
+}
+
+impl GitHubHost {
+    fn default_host() -> Self {
+        Self {
+            host: DEFAULT_GITHUB_HOST.to_string(),
+            api_url: None,
+            login: None,
+        }
+    }
+
+    pub(super) fn api_base_url(&self) -> String {
+        match &self.api_url {
+            Some(url) => url.clone(),
+            None if self.host == DEFAULT_GITHUB_HOST => "https://api.github.com".to_string(),
+            None => format!("https://{}/api/v3", self.host),
+        }
+    }
+}
+
+/// GitHub hosts configured in settings. Empty means github.com only.
+static GITHUB_HOSTS: Mutex<Vec<GitHubHost>> = Mutex::new(Vec::new());
+
+/// Lowercase host name without scheme, path or trailing slash.
+pub(crate) fn normalize_host(host: &str) -> Result<String, String> {
+    let host = host.trim();
+    let host = host
+        .strip_prefix("https://")
+        .or_else(|| host.strip_prefix("http://"))
+        .unwrap_or(host);
+    let host = host.split('/').next().unwrap_or_default().to_lowercase();
+
+    if host.is_empty()
+        || !host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'))
+    {
+        return Err(format!("Invalid GitHub host: {}", host));
+    }
+    Ok(host)
+}
+
+fn normalize_api_url(api_url: &str) -> Result<String, String> {
+    let api_url = api_url.trim().trim_end_matches('/');
+    if !api_url.starts_with("https://") && !api_url.starts_with("http://") {
+        return Err("GitHub API URL must start with http:// or https://".to_string());
+    }
+    Ok(api_url.to_string())
+}
+
+/// All GitHub hosts to query, in configured order.
+pub(crate) fn github_hosts() -> Result<Vec<GitHubHost>, String> {
+    let configured = GITHUB_HOSTS
+        .lock()
+        .map_err(|e| format!("Failed to lock GitHub hosts: {}", e))?
+        .clone();
+
+    if configured.is_empty() {
+        Ok(vec![GitHubHost::default_host()])
+    } else {
+        Ok(configured)
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_github_hosts() -> Result<Vec<GitHubHost>, String> {
+    github_hosts()
+}
+
+fn apply_github_hosts(hosts: Vec<GitHubHost>) -> Result<Vec<GitHubHost>, String> {
+    let mut resolved: Vec<GitHubHost> = Vec::new();
+
+    for host in hosts {
+        let name = normalize_host(&host.host)?;
+        if resolved.iter().any(|existing| existing.host == name) {
+            return Err(format!("GitHub host is configured twice: {}", name));
+        }
+
+        let api_url = match host.api_url.as_deref().map(str::trim) {
+            Some(url) if !url.is_empty() => Some(normalize_api_url(url)?),
+            _ => None,
+        };
+        let login = host
+            .login
+            .map(|login| login.trim().to_string())
+            .filter(|login| !login.is_empty());
+
+        resolved.push(GitHubHost {
+            host: name,
+            api_url,
+            login,
+        });
+    }
+
+    let mut configured = GITHUB_HOSTS
+        .lock()
+        .map_err(|e| format!("Failed to lock GitHub hosts: {}", e))?;
+    *configured = resolved.clone();
+    Ok(resolved)
+}
+
+/// Restore the saved GitHub hosts. Called once during app setup.
+pub(crate) fn load_github_hosts(app: &AppHandle) {
+    let Some(hosts) = load_setting::<Vec<GitHubHost>>(app, SETTING_KEY) else {
+        return;
+    };
+    if let Err(error) = apply_github_hosts(hosts) {
+        eprintln!("Ignoring saved GitHub hosts: {}", error);
+    }
+}
+
+/// Replace the configured GitHub hosts and save them. Passing an empty list
+/// restores github.com only. Returns the effective list of hosts.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_github_hosts(
+    app: AppHandle,
+    hosts: Vec<GitHubHost>,
+) -> Result<Vec<GitHubHost>, String> {
+    let resolved = apply_github_hosts(hosts)?;
+    save_setting(&app, SETTING_KEY, &resolved)?;
+
+    github_hosts()
+}
@@ -0,0 +1,544 @@
+use super::client::{GitHubClient, Page};
+use super::dates::{hours_since, parse_timestamp_minutes};
+use super::hosts::{github_hosts, GitHubHost};
+use super::{
+    all_hosts_failed, clean_title, host_login, is_author, repo_from_api_url, review_state_label,
+    search_issues, CommitInfo, GitHubError, GitHubHostStatus, GitHubPullRequestState, ReviewItem,
+    SearchIssueItem, DETAIL_CONCURRENCY,
+};
+use futures_util::future::join_all;
+use futures_util::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Combined result of the check runs and commit statuses of a pull request's
+/// head commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum GitHubCiStatus {
+    Success,
+    Failure,
+    Pending,
+    /// No checks or statuses reported.
+    None,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubInboxItem {
+    pub title: String,
+    pub url: String,
+    pub repo: String,
+    pub host: String,
+    pub number: u64,
+    pub created_at: String,
+    /// Hours since `created_at`.
+    pub age_hours: u32,
+    /// Set for pull requests.
+    pub pr_state: Option<GitHubPullRequestState>,
+    /// Set for pull requests.
+    pub ci_status: Option<GitHubCiStatus>,
+    /// Reviews by others since the latest commit. Only set for own pull requests.
+    pub new_reviews: u32,
+    /// Label of the latest of `new_reviews`, e.g. "Changes requested".
+    pub latest_review: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubInboxResponse {
+    /// Login on the first host that responded.
+    pub login: String,
+    /// Open pull requests awaiting a review from the user or their teams.
+    pub review_requests: Vec<GitHubInboxItem>,
+    /// Own open pull requests with new reviews or failing checks.
+    pub my_pull_requests: Vec<GitHubInboxItem>,
+    /// Open issues assigned to the user.
+    pub assigned_issues: Vec<GitHubInboxItem>,
+    pub hosts: Vec<GitHubHostStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullDetail {
+    head: PullHead,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullHead {
+    sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitDetail {
+    commit: CommitInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRunsResponse {
+    check_runs: Vec<CheckRun>,
+}
+
+impl Page<CheckRun> for CheckRunsResponse {
+    fn into_items(self) -> Vec<CheckRun> {
+        self.check_runs
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRun {
+    /// "queued", "in_progress" or "completed"
+    status: String,
+    conclusion: Option<String>,
+}
+
+impl CheckRun {
+    fn ci_status(&self) -> GitHubCiStatus {
+        if self.status != "completed" {
+            return GitHubCiStatus::Pending;
+        }
+        match self.conclusion.as_deref() {
+            Some("failure" | "timed_out" | "cancelled" | "action_required") => {
+                GitHubCiStatus::Failure
+            }
+            _ => GitHubCiStatus::Success,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CombinedStatus {
+    /// "success", "failure" or "pending"; "pending" also when there are none
+    state: String,
+    total_count: u32,
+}
+
+/// Inbox of a single host.
+struct HostInbox {
+    login: String,
+    review_requests: Vec<GitHubInboxItem>,
+    my_pull_requests: Vec<GitHubInboxItem>,
+    assigned_issues: Vec<GitHubInboxItem>,
+    /// Set when the details of some pull requests could not be loaded.
+    incomplete: Option<String>,
+}
+
+/// Pull requests, reviews and issues waiting on the user across all
+/// configured hosts, oldest first.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_github_inbox() -> Result<GitHubInboxResponse, GitHubError> {
+    let configured = github_hosts()?;
+    let results = join_all(configured.iter().map(fetch_host_inbox)).await;
+
+    let mut review_requests = Vec::new();
+    let mut my_pull_requests = Vec::new();
+    let mut assigned_issues = Vec::new();
+    let mut hosts = Vec::new();
+    let mut errors = Vec::new();
+
+    for (host, result) in configured.into_iter().zip(results) {
+        match result {
+            Ok(inbox) => {
+                review_requests.extend(inbox.review_requests);
+                my_pull_requests.extend(inbox.my_pull_requests);
+                assigned_issues.extend(inbox.assigned_issues);
+                hosts.push(GitHubHostStatus {
+                    host: host.host,
+                    login: Some(inbox.login),
+                    error: None,
+                    incomplete: inbox.incomplete,
+                });
+            }
+            Err(error) => {
+                errors.push((host.host.clone(), error.clone()));
+                hosts.push(GitHubHostStatus {
+                    host: host.host,
+                    login: None,
+                    error: Some(error),
+                    incomplete: None,
+                });
+            }
+        }
+    }
+
+    let login = match hosts.iter().find_map(|status| status.login.clone()) {
+        Some(login) => login,
+        None => return Err(all_hosts_failed(errors)),
+    };
+
+    for items in [
+        &mut review_requests,
+        &mut my_pull_requests,
+        &mut assigned_issues,
+    ] {
+        sort_oldest_first(items);
+    }
+
+    Ok(GitHubInboxResponse {
+        login,
+        review_requests,
+        my_pull_requests,
+        assigned_issues,
+        hosts,
+    })
+}
+
+fn sort_oldest_first(items: &mut [GitHubInboxItem]) {
+    items.sort_by(|a, b| b.age_hours.cmp(&a.age_hours));
+}
+
+/// Pull requests awaiting the user's review, own pull requests that need
+/// attention (new reviews or failing checks), and a note when the details of
+/// some of them could not be loaded. Each pull request comes with whether
+/// all of its details were loaded.
+fn classify_pull_requests(
+    review_requests: Vec<(GitHubInboxItem, bool)>,
+    authored: Vec<(GitHubInboxItem, bool)>,
+) -> (Vec<GitHubInboxItem>, Vec<GitHubInboxItem>, Option<String>) {
+    let failed = review_requests
+        .iter()
+        .chain(&authored)
+        .filter(|(_, complete)| !complete)
+        .count();
+    let incomplete = (failed > 0).then(|| {
+        format!(
+            "Checks or reviews of {} pull request(s) could not be loaded.",
+            failed
+        )
+    });
+
+    let review_requests = review_requests.into_iter().map(|(item, _)| item).collect();
+    let my_pull_requests = authored
+        .into_iter()
+        .map(|(item, _)| item)
+        .filter(|item| item.new_reviews > 0 || item.ci_status == Some(GitHubCiStatus::Failure))
+        .collect();
+
+    (review_requests, my_pull_requests, incomplete)
+}
+
+async fn fetch_host_inbox(host: &GitHubHost) -> Result<HostInbox, GitHubError> {
+    let client = GitHubClient::for_host(host).await?;
+    let login = host_login(&client, host).await?;
+
+    // `review-requested` also matches requests made to the user's teams
+    let review_query = format!("is:pr is:open archived:false review-requested:{}", login);
+    let authored_query = format!("is:pr is:open archived:false author:{}", login);
+    let assigned_query = format!("is:issue is:open archived:false assignee:{}", login);
+
+    let (review_requests, authored, assigned) = futures_util::try_join!(
+        search_issues(&client, &review_query),
+        search_issues(&client, &authored_query),
+        search_issues(&client, &assigned_query),
+    )?;
+
+    let review_requests: Vec<(GitHubInboxItem, bool)> = stream::iter(review_requests)
+        .map(|pr| inbox_pull_request(&client, &login, pr, false))
+        .buffered(DETAIL_CONCURRENCY)
+        .collect()
+        .await;
+
+    let authored: Vec<(GitHubInboxItem, bool)> = stream::iter(authored)
+        .map(|pr| inbox_pull_request(&client, &login, pr, true))
+        .buffered(DETAIL_CONCURRENCY)
+        .collect()
+        .await;
+
+    let (review_requests, my_pull_requests, incomplete) =
+        classify_pull_requests(review_requests, authored);
+
+    let assigned_issues = assigned
+        .into_iter()
+        .map(|issue| inbox_item(client.host(), issue))
+        .collect();
+
+    Ok(HostInbox {
+        login,
+        review_requests,
+        my_pull_requests,
+        assigned_issues,
+        incomplete,
+    })
+}
+
+fn inbox_item(host: &str, item: SearchIssueItem) -> GitHubInboxItem {
+    let pr_state = item.pull_request_state();
+    let fallback = if pr_state.is_some() {
+        "Untitled pull request"
+    } else {
+        "Untitled issue"
+    };
+    GitHubInboxItem {
+        title: clean_title(item.title, fallback),
+        url: item.html_url,
+        repo: repo_from_api_url(&item.repository_url),
+        host: host.to_string(),
+        number: item.number,
+        age_hours: hours_since(&item.created_at).unwrap_or_default(),
+        created_at: item.created_at,
+        pr_state,
+        ci_status: None,
+        new_reviews: 0,
+        latest_review: None,
+    }
+}
+
+/// Inbox item for a pull request with its CI status, and for `own` pull
+/// requests the reviews by others since the latest commit. Each detail is
+/// best effort: a failed call leaves `ci_status` unset or `new_reviews` at
+/// zero rather than dropping the item. Also returns whether every detail
+/// was loaded.
+async fn inbox_pull_request(
+    client: &GitHubClient,
+    login: &str,
+    pr: SearchIssueItem,
+    own: bool,
+) -> (GitHubInboxItem, bool) {
+    let mut item = inbox_item(client.host(), pr);
+
+    let path = format!("repos/{}/pulls/{}", item.repo, item.number);
+    let detail: PullDetail = match client.get(&path, &[], None).await {
+        Ok(detail) => detail,
+        Err(_) => return (item, false),
+    };
+    item.ci_status = ci_status(client, &item.repo, &detail.head.sha).await.ok();
+    let complete = item.ci_status.is_some();
+
+    if own {
+        let path = format!("repos/{}/commits/{}", item.repo, detail.head.sha);
+        let reviews = futures_util::try_join!(
+            client.get::<CommitDetail>(&path, &[], None),
+            client.get_pages::<Vec<ReviewItem>, _>(
+                &format!("repos/{}/pulls/{}/reviews", item.repo, item.number),
+                &[("per_page", "100")],
+                None,
+            ),
+        );
+        let (head, reviews) = match reviews {
+            Ok(reviews) => reviews,
+            Err(_) => return (item, false),
+        };
+
+        let pushed_at = head
+            .commit
+            .committer
+            .and_then(|committer| committer.date)
+            .and_then(|date| parse_timestamp_minutes(&date))
+            .unwrap_or_default();
+        let new_reviews = reviews_since(&reviews, login, pushed_at);
+
+        item.new_reviews = new_reviews.len() as u32;
+        item.latest_review = new_reviews
+            .last()
+            .map(|review| review_state_label(&review.state).to_string());
+    }
+
+    (item, complete)
+}
+
+/// Reviews by others submitted at or after `pushed_at` (minutes since the
+/// epoch), oldest first as GitHub lists them.
+fn reviews_since<'a>(
+    reviews: &'a [ReviewItem],
+    login: &str,
+    pushed_at: i64,
+) -> Vec<&'a ReviewItem> {
+    reviews
+        .iter()
+        .filter(|review| review.user.is_some() && !is_author(&review.user, login))
+        .filter(|review| {
+            review
+                .submitted_at
+                .as_deref()
+                .and_then(parse_timestamp_minutes)
+                .map(|submitted| submitted >= pushed_at)
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+/// Overall status from check runs and the combined commit status: any
+/// failure wins, then anything pending.
+fn combine_ci_status(check_runs: &[CheckRun], status: &CombinedStatus) -> GitHubCiStatus {
+    let mut states: Vec<GitHubCiStatus> = check_runs.iter().map(CheckRun::ci_status).collect();
+    if status.total_count > 0 {
+        states.push(match status.state.as_str() {
+            "success" => GitHubCiStatus::Success,
+            "pending" => GitHubCiStatus::Pending,
+            _ => GitHubCiStatus::Failure,
+        });
+    }
+
+    if states.is_empty() {
+        GitHubCiStatus::None
+    } else if states.contains(&GitHubCiStatus::Failure) {
+        GitHubCiStatus::Failure
+    } else if states.contains(&GitHubCiStatus::Pending) {
+        GitHubCiStatus::Pending
+    } else {
+        GitHubCiStatus::Success
+    }
+}
+
+/// CI status of a commit from its check runs (GitHub Actions, apps) and
+/// commit statuses (external CI).
+async fn ci_status(
+    client: &GitHubClient,
+    repo: &str,
+    sha: &str,
+) -> Result<GitHubCiStatus, GitHubError> {
+    let (check_runs, status) = futures_util::try_join!(
+        client.get_pages::<CheckRunsResponse, _>(
+            &format!("repos/{}/commits/{}/check-runs", repo, sha),
+            &[("per_page", "100")],
+            None,
+        ),
+        client.get::<CombinedStatus>(&format!("repos/{}/commits/{}/status", repo, sha), &[], None),
+    )?;
+
+    Ok(combine_ci_status(&check_runs, &status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pull_request(number: u64, age_hours: u32) -> GitHubInboxItem {
+        GitHubInboxItem {
+            title: format!("PR {}", number),
+            url: String::new(),
+            repo: "acme/api".to_string(),
+            host: "github.com".to_string(),
+            number,
+            created_at: String::new(),
+            age_hours,
+            pr_state: Some(GitHubPullRequestState::Open),
+            ci_status: Some(GitHubCiStatus::Success),
+            new_reviews: 0,
+            latest_review: None,
+        }
+    }
+
+    fn check_run(status: &str, conclusion: Option<&str>) -> CheckRun {
+        CheckRun {
+            status: status.to_string(),
+            conclusion: conclusion.map(str::to_string),
+        }
+    }
+
+    fn combined(state: &str, total_count: u32) -> CombinedStatus {
+        CombinedStatus {
+            state: state.to_string(),
+            total_count,
+        }
+    }
+
+    #[test]
+    fn combines_check_runs_and_statuses() {
+        let passed = check_run("completed", Some("success"));
+        assert_eq!(
+            combine_ci_status(&[], &combined("pending", 0)),
+            GitHubCiStatus::None
+        );
+        assert_eq!(
+            combine_ci_status(&[passed], &combined("pending", 0)),
+            GitHubCiStatus::Success
+        );
+        assert_eq!(
+            combine_ci_status(
+                &[
+                    check_run("in_progress", None),
+                    check_run("completed", Some("success"))
+                ],
+                &combined("success", 1)
+            ),
+            GitHubCiStatus::Pending
+        );
+        assert_eq!(
+            combine_ci_status(
+                &[
+                    check_run("in_progress", None),
+                    check_run("completed", Some("timed_out"))
+                ],
+                &combined("success", 1)
+            ),
+            GitHubCiStatus::Failure
+        );
+        assert_eq!(
+            combine_ci_status(
+                &[check_run("completed", Some("skipped"))],
+                &combined("failure", 2)
+            ),
+            GitHubCiStatus::Failure
+        );
+    }
+
+    #[test]
+    fn counts_reviews_by_others_since_the_last_push() {
+        let reviews: Vec<ReviewItem> = serde_json::from_str(
+            r#"[
+                {"user":{"login":"lee"},"state":"COMMENTED","html_url":"","submitted_at":"2026-01-22T00:59:00Z"},
+                {"user":{"login":"Kim"},"state":"COMMENTED","html_url":"","submitted_at":"2026-01-22T02:00:00Z"},
+                {"user":{"login":"lee"},"state":"APPROVED","html_url":"","submitted_at":"2026-01-22T01:00:00Z"},
+                {"user":null,"state":"COMMENTED","html_url":"","submitted_at":"2026-01-22T03:00:00Z"},
+                {"user":{"login":"park"},"state":"PENDING","html_url":""},
+                {"user":{"login":"park"},"state":"CHANGES_REQUESTED","html_url":"","submitted_at":"2026-01-22T04:00:00Z"}
+            ]"#,
+        )
+        .unwrap();
+        let pushed_at = parse_timestamp_minutes("2026-01-22T01:00:00Z").unwrap();
+
+        let new_reviews = reviews_since(&reviews, "kim", pushed_at);
+        let states: Vec<&str> = new_reviews
+            .iter()
+            .map(|review| review.state.as_str())
+            .collect();
+        assert_eq!(states, ["APPROVED", "CHANGES_REQUESTED"]);
+    }
+
+    #[test]
+    fn keeps_own_pull_requests_that_need_attention() {
+        let reviewed = GitHubInboxItem {
+            new_reviews: 1,
+            ..pull_request(1, 5)
+        };
+        let failing = GitHubInboxItem {
+            ci_status: Some(GitHubCiStatus::Failure),
+            ..pull_request(2, 5)
+        };
+        let quiet = pull_request(3, 5);
+        let unknown = GitHubInboxItem {
+            ci_status: None,
+            ..pull_request(4, 5)
+        };
+
+        let (review_requests, mine, incomplete) = classify_pull_requests(
+            vec![(pull_request(10, 1), true), (pull_request(11, 2), false)],
+            vec![
+                (reviewed, true),
+                (failing, true),
+                (quiet, true),
+                (unknown, false),
+            ],
+        );
+
+        let numbers =
+            |items: &[GitHubInboxItem]| items.iter().map(|item| item.number).collect::<Vec<_>>();
+        assert_eq!(numbers(&review_requests), [10, 11]);
+        assert_eq!(numbers(&mine), [1, 2]);
+        assert_eq!(
+            incomplete.as_deref(),
+            Some("Checks or reviews of 2 pull request(s) could not be loaded.")
+        );
+
+        let (_, _, incomplete) =
+            classify_pull_requests(vec![(pull_request(10, 1), true)], Vec::new());
+        assert_eq!(incomplete, None);
+    }
+
+    #[test]
+    fn sorts_oldest_first() {
+        let mut items = vec![pull_request(1, 3), pull_request(2, 48), pull_request(3, 0)];
+        sort_oldest_first(&mut items);
+        let numbers: Vec<u64> = items.iter().map(|item| item.number).collect();
+        assert_eq!(numbers, [2, 1, 3]);
+    }
+}
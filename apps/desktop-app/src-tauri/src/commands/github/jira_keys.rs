@@ -0,0 +1,51 @@
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Prefixes of common identifiers that look like issue keys, e.g. "UTF-8".
+const NOT_PROJECT_KEYS: &[&str] = &["AES", "CVE", "ISO", "RFC", "SHA", "UTF"];
+
+static JIRA_KEY_RE: OnceLock<Regex> = OnceLock::new();
+
+/// Jira issue keys ("ABC-123") found in `texts`, in order of first appearance.
+pub(super) fn jira_keys<'a>(texts: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let re = JIRA_KEY_RE.get_or_init(|| {
+        Regex::new(r"(?:^|[^A-Za-z0-9])([A-Z][A-Z0-9_]+)-([1-9][0-9]*)\b")
+            .expect("valid Jira key regex")
+    });
+
+    let mut keys: Vec<String> = Vec::new();
+    for text in texts {
+        for captures in re.captures_iter(text) {
+            let project = &captures[1];
+            if NOT_PROJECT_KEYS.contains(&project) {
+                continue;
+            }
+            let key = format!("{}-{}", project, &captures[2]);
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_keys_in_branch_and_message() {
+        assert_eq!(
+            jira_keys([
+                "feature/ABC-123-login",
+                "ABC-123: Fix login\n\nAlso touches PLAT2-7 and utf-8 / UTF-8 handling",
+            ]),
+            ["ABC-123", "PLAT2-7"]
+        );
+    }
+
+    #[test]
+    fn ignores_lowercase_and_embedded_keys() {
+        assert!(jira_keys(["abc-123", "xABC-12", "ABC-0", "SHA-256"]).is_empty());
+    }
+}
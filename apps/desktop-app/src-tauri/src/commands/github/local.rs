@@ -0,0 +1,392 @@
+use super::dates::kst_day_start_utc;
+use super::{
+    clean_title, validate_date, DateRange, GitHubActivityItem, GitHubActivityKind,
+    GitHubCommitDetails,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use walkdir::WalkDir;
+
+/// Directory levels below a root searched for repositories.
+const MAX_SCAN_DEPTH: usize = 4;
+/// Host reported for repositories without a recognizable remote.
+const LOCAL_HOST: &str = "local";
+const RECORD_SEPARATOR: char = '\x1e';
+const FIELD_SEPARATOR: char = '\x1f';
+/// Ends the message, which may span lines, before the `--shortstat` output.
+const MESSAGE_END: char = '\x1d';
+/// Hash, author date, author email, ref the commit was reached from, message.
+const LOG_FORMAT: &str = "--format=%x1e%H%x1f%aI%x1f%ae%x1f%S%x1f%B%x1d";
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct LocalGitRepoStatus {
+    pub path: String,
+    /// Why this repository could not be read. Other repositories are still returned.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct LocalGitActivityResponse {
+    pub date: String,
+    /// Commits, newest first. `url` is empty for repositories without a remote.
+    pub items: Vec<GitHubActivityItem>,
+    pub repos: Vec<LocalGitRepoStatus>,
+}
+
+/// Commits authored on `date` (KST) in the git repositories found under
+/// `repo_roots`. Without `author_emails`, each repository's `user.email` is used.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_local_git_activity(
+    date: String,
+    repo_roots: Vec<String>,
+    author_emails: Vec<String>,
+) -> Result<LocalGitActivityResponse, String> {
+    let date = date.trim().to_string();
+    validate_date(&date)?;
+
+    let emails: Vec<String> = author_emails
+        .iter()
+        .map(|email| email.trim().to_lowercase())
+        .filter(|email| !email.is_empty())
+        .collect();
+
+    tauri::async_runtime::spawn_blocking(move || scan_activity(date, &repo_roots, &emails))
+        .await
+        .map_err(|e| format!("Local git task failed: {}", e))?
+}
+
+fn scan_activity(
+    date: String,
+    repo_roots: &[String],
+    emails: &[String],
+) -> Result<LocalGitActivityResponse, String> {
+    let range = DateRange::day(&date);
+    let since = kst_day_start_utc(&date).ok_or("Date must be in YYYY-MM-DD format")?;
+
+    let mut items = Vec::new();
+    let mut repos = Vec::new();
+
+    for root in repo_roots.iter().map(|root| root.trim()) {
+        if !root.is_empty() && !Path::new(root).is_dir() {
+            repos.push(LocalGitRepoStatus {
+                path: root.to_string(),
+                error: Some("Directory not found".to_string()),
+            });
+        }
+    }
+
+    for repo in find_repositories(repo_roots) {
+        let error = match read_repo_activity(&repo, range, &since, emails) {
+            Ok(repo_items) => {
+                items.extend(repo_items);
+                None
+            }
+            Err(error) => Some(error),
+        };
+        repos.push(LocalGitRepoStatus {
+            path: repo.to_string_lossy().to_string(),
+            error,
+        });
+    }
+
+    items.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    Ok(LocalGitActivityResponse {
+        date: date.clone(),
+        items,
+        repos,
+    })
+}
+
+/// Repositories under `roots`, without descending into repositories, hidden
+/// directories or dependency folders.
+fn find_repositories(roots: &[String]) -> Vec<PathBuf> {
+    let mut repos = Vec::new();
+
+    for root in roots.iter().map(|root| root.trim()) {
+        if root.is_empty() {
+            continue;
+        }
+
+        let mut entries = WalkDir::new(root).max_depth(MAX_SCAN_DEPTH).into_iter();
+        while let Some(entry) = entries.next() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(_) => continue,
+            };
+            if !entry.file_type().is_dir() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy();
+            let skipped = entry.depth() > 0
+                && (name.starts_with('.') || matches!(name.as_ref(), "node_modules" | "target"));
+            if skipped {
+                entries.skip_current_dir();
+                continue;
+            }
+
+            // `.git` is a file in worktrees and submodules
+            if entry.path().join(".git").exists() {
+                repos.push(entry.into_path());
+                entries.skip_current_dir();
+            }
+        }
+    }
+
+    repos.sort();
+    repos.dedup();
+    repos
+}
+
+fn read_repo_activity(
+    repo: &Path,
+    range: DateRange<'_>,
+    since: &str,
+    emails: &[String],
+) -> Result<Vec<GitHubActivityItem>, String> {
+    let repo_emails = if emails.is_empty() {
+        let email = run_git(repo, &["config", "user.email"])
+            .ok()
+            .map(|email| email.trim().to_lowercase())
+            .filter(|email| !email.is_empty())
+            .ok_or("No author email configured for this repository")?;
+        vec![email]
+    } else {
+        emails.to_vec()
+    };
+
+    // `--since` filters on the committer date, which is never before the
+    // author date, so the author date is checked below
+    let since_arg = format!("--since={}", since);
+    let output = run_git(
+        repo,
+        &[
+            "log",
+            "--all",
+            "--source",
+            "--no-merges",
+            "--shortstat",
+            &since_arg,
+            LOG_FORMAT,
+        ],
+    )?;
+
+    let remote = run_git(repo, &["config", "--get", "remote.origin.url"])
+        .ok()
+        .and_then(|url| parse_remote(url.trim()));
+    let (host, repo_name) = match &remote {
+        Some((host, repo_name)) => (host.clone(), repo_name.clone()),
+        None => (
+            LOCAL_HOST.to_string(),
+            repo.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| repo.to_string_lossy().to_string()),
+        ),
+    };
+
+    Ok(parse_log(&output)
+        .into_iter()
+        .filter(|commit| repo_emails.contains(&commit.email.to_lowercase()))
+        .filter(|commit| range.contains(&commit.timestamp))
+        .map(|commit| {
+            let url = match &remote {
+                Some((host, repo_name)) => {
+                    format!("https://{}/{}/commit/{}", host, repo_name, commit.sha)
+                }
+                None => String::new(),
+            };
+            let short_sha = commit.sha.chars().take(7).collect::<String>();
+            let summary = match &commit.branch {
+                Some(branch) => format!("{} · {}", short_sha, branch),
+                None => short_sha,
+            };
+
+            let subject = commit
+                .message
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            let mut details =
+                GitHubCommitDetails::new(commit.sha, &commit.message, commit.branch, None);
+            details.files_changed = Some(commit.files_changed);
+            details.additions = Some(commit.additions);
+            details.deletions = Some(commit.deletions);
+
+            GitHubActivityItem {
+                kind: GitHubActivityKind::Commit,
+                title: clean_title(subject, "Commit"),
+                url,
+                repo: repo_name.clone(),
+                host: host.clone(),
+                timestamp: commit.timestamp,
+                number: None,
+                summary: Some(summary),
+                pr_state: None,
+                commit: Some(details),
+            }
+        })
+        .collect())
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct LocalCommit {
+    sha: String,
+    timestamp: String,
+    email: String,
+    branch: Option<String>,
+    /// Full message, subject first.
+    message: String,
+    files_changed: u32,
+    additions: u32,
+    deletions: u32,
+}
+
+/// Parse `git log` output written with `LOG_FORMAT` and `--shortstat`.
+fn parse_log(output: &str) -> Vec<LocalCommit> {
+    output
+        .split(RECORD_SEPARATOR)
+        .filter_map(|record| {
+            let (header, stat) = record.split_once(MESSAGE_END).unwrap_or((record, ""));
+            let mut fields = header.splitn(5, FIELD_SEPARATOR);
+            let sha = fields.next()?.trim();
+            if sha.is_empty() {
+                return None;
+            }
+
+            let mut commit = LocalCommit {
+                sha: sha.to_string(),
+                timestamp: fields.next()?.to_string(),
+                email: fields.next()?.to_string(),
+                branch: fields.next().and_then(branch_name),
+                message: fields.next().unwrap_or_default().trim().to_string(),
+                ..LocalCommit::default()
+            };
+
+            // e.g. " 3 files changed, 10 insertions(+), 2 deletions(-)"
+            if let Some(stat) = stat.lines().find(|line| line.contains("changed")) {
+                for part in stat.split(',') {
+                    let count = part
+                        .split_whitespace()
+                        .next()
+                        .and_then(|n| n.parse::<u32>().ok())
+                        .unwrap_or(0);
+                    if part.contains("file") {
+                        commit.files_changed = count;
+                    } else if part.contains("insertion") {
+                        commit.additions = count;
+                    } else if part.contains("deletion") {
+                        commit.deletions = count;
+                    }
+                }
+            }
+
+            Some(commit)
+        })
+        .collect()
+}
+
+/// Branch of a `--source` ref such as "refs/heads/main". `None` for a detached HEAD.
+fn branch_name(source: &str) -> Option<String> {
+    let source = source.trim();
+    let branch = source
+        .strip_prefix("refs/heads/")
+        .or_else(|| source.strip_prefix("refs/remotes/"))
+        .or_else(|| source.strip_prefix("refs/tags/"))
+        .unwrap_or(source);
+    if branch.is_empty() || branch == "HEAD" {
+        return None;
+    }
+    Some(branch.to_string())
+}
+
+/// (host, "owner/repo") of an SSH or HTTP(S) remote URL.
+fn parse_remote(url: &str) -> Option<(String, String)> {
+    let (host, path) = if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    {
+        let (authority, path) = rest.split_once('/')?;
+        let host = authority.rsplit('@').next()?;
+        (host.to_string(), path)
+    } else if let Some(rest) = url.strip_prefix("ssh://") {
+        let (authority, path) = rest.split_once('/')?;
+        let host = authority.rsplit('@').next()?;
+        // The SSH port is not the web port
+        let host = host.split(':').next()?;
+        (host.to_string(), path)
+    } else {
+        // scp-like syntax, e.g. "git@github.com:owner/repo.git"
+        let (authority, path) = url.split_once(':')?;
+        let host = authority.rsplit('@').next()?;
+        (host.to_string(), path)
+    };
+
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    if host.is_empty() || !path.contains('/') {
+        return None;
+    }
+    Some((host.to_lowercase(), path.to_string()))
+}
+
+fn run_git(repo: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .map_err(|error| match error.kind() {
+            ErrorKind::NotFound => "Git not found. Install git and add it to PATH.".to_string(),
+            _ => format!("Failed to run git: {}", error),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if stderr.is_empty() {
+            "Git command failed".to_string()
+        } else {
+            stderr
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_log_with_shortstat() {
+        let output = "\x1eabc1234def\x1f2024-05-02T10:00:00+09:00\x1fme@example.com\x1frefs/heads/feature/x\x1fAdd thing\n\nNothing changed here\n\x1d\n\n 3 files changed, 10 insertions(+), 2 deletions(-)\n\x1edef5678abc\x1f2024-05-02T09:00:00+09:00\x1fme@example.com\x1fHEAD\x1fFix\n\x1d\n\n 1 file changed, 1 deletion(-)\n";
+        let commits = parse_log(output);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].branch.as_deref(), Some("feature/x"));
+        assert_eq!(commits[0].message, "Add thing\n\nNothing changed here");
+        assert_eq!(
+            (
+                commits[0].files_changed,
+                commits[0].additions,
+                commits[0].deletions
+            ),
+            (3, 10, 2)
+        );
+        assert_eq!(commits[1].branch, None);
+        assert_eq!((commits[1].additions, commits[1].deletions), (0, 1));
+    }
+
+    #[test]
+    fn parses_remote_urls() {
+        let expected = Some(("github.com".to_string(), "owner/repo".to_string()));
+        assert_eq!(parse_remote("git@github.com:owner/repo.git"), expected);
+        assert_eq!(parse_remote("https://github.com/owner/repo.git"), expected);
+        assert_eq!(parse_remote("ssh://git@github.com:22/owner/repo"), expected);
+        assert_eq!(parse_remote("/srv/git/repo.git"), None);
+    }
+}
@@ -0,0 +1,1187 @@
+use futures_util::future::join_all;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use tauri::AppHandle;
+
+mod cache;
+mod client;
+mod dates;
+mod filter;
+mod hosts;
+mod inbox;
+mod jira_keys;
+mod local;
+mod range;
+mod source;
+
+use cache::{
+    activity_cache_dir, cache_login, cached_login, read_cached_activity, write_cached_activity,
+    CachedHostActivity,
+};
+use client::{GitHubClient, Page};
+use dates::{day_span, kst_day_start_utc, kst_today};
+use filter::RepoFilter;
+use hosts::GitHubHost;
+use jira_keys::jira_keys;
+
+pub(crate) use cache::forget_github_login;
+pub use client::GitHubError;
+pub(crate) use dates::{add_days, kst_date_of, parse_timestamp_minutes};
+pub use filter::GitHubRepoFilter;
+pub use hosts::{get_github_hosts, set_github_hosts};
+pub(crate) use hosts::{github_hosts, load_github_hosts, normalize_host, DEFAULT_GITHUB_HOST};
+pub use inbox::*;
+pub use local::*;
+pub use range::*;
+pub use source::{GitHubSourceSettings, LocalGitSourceSettings};
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum GitHubActivityKind {
+    Commit,
+    PullRequest,
+    Review,
+    Comment,
+    Merged,
+    IssueOpened,
+    IssueClosed,
+    Released,
+}
+
+impl GitHubActivityKind {
+    /// Serialized name of the kind.
+    fn as_str(&self) -> &'static str {
+        match self {
+            GitHubActivityKind::Commit => "commit",
+            GitHubActivityKind::PullRequest => "pull_request",
+            GitHubActivityKind::Review => "review",
+            GitHubActivityKind::Comment => "comment",
+            GitHubActivityKind::Merged => "merged",
+            GitHubActivityKind::IssueOpened => "issue_opened",
+            GitHubActivityKind::IssueClosed => "issue_closed",
+            GitHubActivityKind::Released => "released",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum GitHubPullRequestState {
+    Open,
+    Draft,
+    Merged,
+    Closed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubActivityItem {
+    pub kind: GitHubActivityKind,
+    pub title: String,
+    pub url: String,
+    pub repo: String,
+    /// GitHub host the item was fetched from, e.g. "github.com".
+    pub host: String,
+    pub timestamp: String,
+    pub number: Option<u64>,
+    pub summary: Option<String>,
+    /// Current state of the pull request the item belongs to, if any.
+    pub pr_state: Option<GitHubPullRequestState>,
+    /// Set for commits.
+    pub commit: Option<GitHubCommitDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GitHubCommitDetails {
+    pub sha: String,
+    /// Message lines after the subject.
+    pub body: Option<String>,
+    /// Branch the commit was found on locally, or the head branch of its pull request.
+    pub branch: Option<String>,
+    /// Title of the pull request the commit belongs to. Not known for local repositories.
+    pub pull_request_title: Option<String>,
+    /// Unknown for commits touching 300 or more files, and when not looked up.
+    pub files_changed: Option<u32>,
+    pub additions: Option<u32>,
+    pub deletions: Option<u32>,
+    /// Jira issue keys ("ABC-123") in the branch, message or pull request title.
+    pub jira_keys: Vec<String>,
+}
+
+impl GitHubCommitDetails {
+    /// Details of a commit with the Jira keys of `branch`, `message` and
+    /// `pull_request_title` filled in.
+    fn new(
+        sha: String,
+        message: &str,
+        branch: Option<String>,
+        pull_request_title: Option<String>,
+    ) -> Self {
+        let jira_keys = jira_keys(
+            [
+                branch.as_deref(),
+                Some(message),
+                pull_request_title.as_deref(),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+        Self {
+            sha,
+            body: commit_body(message),
+            branch,
+            pull_request_title,
+            files_changed: None,
+            additions: None,
+            deletions: None,
+            jira_keys,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubHostStatus {
+    pub host: String,
+    pub login: Option<String>,
+    /// Why this host failed. Items from the other hosts are still returned.
+    pub error: Option<GitHubError>,
+    /// Why some of this host's activity may be missing from the items.
+    pub incomplete: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubActivityResponse {
+    /// Login on the first host that responded.
+    pub login: String,
+    pub date: String,
+    pub items: Vec<GitHubActivityItem>,
+    pub hosts: Vec<GitHubHostStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse<T> {
+    items: Vec<T>,
+}
+
+impl<T> Page<T> for SearchResponse<T> {
+    fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Concurrent requests when fetching reviews or comments of matched issues.
+const DETAIL_CONCURRENCY: usize = 6;
+/// Characters of a review or comment body kept in `summary`.
+const EXCERPT_CHARS: usize = 120;
+/// Commits, newest first, that get their diffstat and pull request looked up.
+/// Each costs two requests; the rest keep the details of the search result.
+const MAX_ENRICHED_COMMITS: usize = 30;
+/// Days after a range in which reviewed or commented issues are still looked
+/// at. Activity on issues that were updated again later is missed.
+const UPDATED_WINDOW_DAYS: i64 = 30;
+/// The events API only returns events of the last 90 days, at most 300.
+const EVENTS_WINDOW_DAYS: i64 = 90;
+const MAX_EVENTS: usize = 300;
+const EVENTS_WINDOW_NOTE: &str =
+    "Closed issues and releases are only known for the last 90 days (at most 300 events).";
+
+#[derive(Debug, Deserialize)]
+struct SearchIssueItem {
+    title: String,
+    html_url: String,
+    number: u64,
+    repository_url: String,
+    created_at: String,
+    /// "open" or "closed"
+    state: String,
+    draft: Option<bool>,
+    pull_request: Option<serde_json::Value>,
+}
+
+impl SearchIssueItem {
+    fn merged_at(&self) -> Option<&str> {
+        self.pull_request.as_ref()?.get("merged_at")?.as_str()
+    }
+
+    /// `None` for issues.
+    fn pull_request_state(&self) -> Option<GitHubPullRequestState> {
+        self.pull_request.as_ref()?;
+        Some(if self.merged_at().is_some() {
+            GitHubPullRequestState::Merged
+        } else if self.state == "closed" {
+            GitHubPullRequestState::Closed
+        } else if self.draft.unwrap_or(false) {
+            GitHubPullRequestState::Draft
+        } else {
+            GitHubPullRequestState::Open
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct UserRef {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewItem {
+    user: Option<UserRef>,
+    state: String,
+    body: Option<String>,
+    html_url: String,
+    /// Missing for pending reviews
+    submitted_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommentItem {
+    user: Option<UserRef>,
+    body: Option<String>,
+    html_url: String,
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    repo: EventRepo,
+    payload: serde_json::Value,
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventRepo {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssuesEventPayload {
+    action: String,
+    issue: EventIssue,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventIssue {
+    number: u64,
+    title: String,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseEventPayload {
+    action: String,
+    release: EventRelease,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventRelease {
+    name: Option<String>,
+    tag_name: String,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchCommitItem {
+    sha: String,
+    html_url: String,
+    commit: CommitInfo,
+    repository: RepositoryInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitInfo {
+    message: String,
+    author: Option<CommitAuthor>,
+    committer: Option<CommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitAuthor {
+    date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryInfo {
+    full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitStatsResponse {
+    stats: Option<CommitStats>,
+    /// Only counted; the API lists at most `MAX_COMMIT_FILES` files.
+    #[serde(default)]
+    files: Vec<serde::de::IgnoredAny>,
+}
+
+/// Files listed for a commit. A full list means the real count is unknown.
+const MAX_COMMIT_FILES: usize = 300;
+
+#[derive(Debug, Deserialize)]
+struct CommitStats {
+    additions: u32,
+    deletions: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitPullRequest {
+    title: String,
+    head: PullRequestRef,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullRequestRef {
+    #[serde(rename = "ref")]
+    ref_name: String,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_github_activity(
+    app: AppHandle,
+    date: String,
+    force_refresh: Option<bool>,
+    filter: Option<GitHubRepoFilter>,
+) -> Result<GitHubActivityResponse, GitHubError> {
+    let date = date.trim().to_string();
+    // Date input is expected to be KST (YYYY-MM-DD) from the frontend.
+    validate_date(&date)?;
+    let filter = RepoFilter::new(&filter.unwrap_or_default())?;
+
+    fetch_day_activity(&app, &date, force_refresh.unwrap_or(false), &filter).await
+}
+
+/// Activity on `date`; past days are served from the disk cache unless
+/// `force_refresh` is set.
+async fn fetch_day_activity(
+    app: &AppHandle,
+    date: &str,
+    force_refresh: bool,
+    filter: &RepoFilter,
+) -> Result<GitHubActivityResponse, GitHubError> {
+    // Past days no longer change, so they are served from the disk cache
+    let cache_dir = activity_cache_dir(app).ok();
+    let cache = match &cache_dir {
+        Some(dir) if date < kst_today().as_str() => Some(DayCache {
+            dir: dir.as_path(),
+            read: !force_refresh,
+        }),
+        _ => None,
+    };
+
+    fetch_activity(date, cache.as_ref(), filter).await
+}
+
+pub(crate) fn validate_date(date: &str) -> Result<(), String> {
+    let re = Regex::new(r"^\d{4}-\d{2}-\d{2}$").map_err(|e| e.to_string())?;
+    if !re.is_match(date) {
+        return Err("Date must be in YYYY-MM-DD format".to_string());
+    }
+    Ok(())
+}
+
+fn validate_login(login: &str) -> Result<(), String> {
+    let re = Regex::new(r"^[A-Za-z0-9-]+$").map_err(|e| e.to_string())?;
+    if !re.is_match(login) {
+        return Err("Unexpected GitHub login format".to_string());
+    }
+    Ok(())
+}
+
+/// Disk cache of a past day's activity.
+struct DayCache<'a> {
+    dir: &'a Path,
+    /// Serve cached results; `false` refetches and overwrites them.
+    read: bool,
+}
+
+/// Inclusive range of KST dates ("YYYY-MM-DD").
+#[derive(Debug, Clone, Copy)]
+struct DateRange<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+impl<'a> DateRange<'a> {
+    fn day(date: &'a str) -> Self {
+        Self {
+            from: date,
+            to: date,
+        }
+    }
+
+    fn contains(&self, timestamp: &str) -> bool {
+        kst_date_of(timestamp)
+            .map(|date| self.from <= date.as_str() && date.as_str() <= self.to)
+            .unwrap_or(false)
+    }
+
+    /// Search qualifier value covering the range in KST, e.g. for `created:`.
+    fn qualifier(&self) -> String {
+        format!("{}T00:00:00+09:00..{}T23:59:59+09:00", self.from, self.to)
+    }
+}
+
+/// Activity of one host.
+struct HostActivity {
+    login: String,
+    items: Vec<GitHubActivityItem>,
+    /// Why some activity may be missing.
+    incomplete: Option<String>,
+}
+
+/// Activity merged across all configured hosts.
+struct HostsActivity {
+    /// Login on the first host that responded.
+    login: String,
+    items: Vec<GitHubActivityItem>,
+    hosts: Vec<GitHubHostStatus>,
+}
+
+/// Activity of all hosts in repositories matching `filter`.
+async fn fetch_all_hosts(
+    range: DateRange<'_>,
+    cache: Option<&DayCache<'_>>,
+    filter: &RepoFilter,
+) -> Result<HostsActivity, GitHubError> {
+    let configured = github_hosts()?;
+    let results = join_all(
+        configured
+            .iter()
+            .map(|host| fetch_host_activity(host, range, cache, filter)),
+    )
+    .await;
+
+    let mut items = Vec::new();
+    let mut hosts = Vec::new();
+    let mut errors = Vec::new();
+
+    // Merge activity across hosts; one unreachable host does not hide the rest
+    for (host, result) in configured.into_iter().zip(results) {
+        match result {
+            Ok(activity) => {
+                items.extend(activity.items);
+                hosts.push(GitHubHostStatus {
+                    host: host.host,
+                    login: Some(activity.login),
+                    error: None,
+                    incomplete: activity.incomplete,
+                });
+            }
+            Err(error) => {
+                errors.push((host.host.clone(), error.clone()));
+                hosts.push(GitHubHostStatus {
+                    host: host.host,
+                    login: None,
+                    error: Some(error),
+                    incomplete: None,
+                });
+            }
+        }
+    }
+
+    let login = match hosts.iter().find_map(|status| status.login.clone()) {
+        Some(login) => login,
+        None => return Err(all_hosts_failed(errors)),
+    };
+
+    // Wildcards, events and cached days are not narrowed by the search itself
+    items.retain(|item| filter.matches(&item.repo));
+    items.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    Ok(HostsActivity {
+        login,
+        items,
+        hosts,
+    })
+}
+
+/// Error reported when every host failed, from (host, error) pairs.
+fn all_hosts_failed(mut errors: Vec<(String, GitHubError)>) -> GitHubError {
+    // Keep a single host's error as-is (e.g. rate limited)
+    if errors.len() == 1 {
+        return errors.remove(0).1;
+    }
+    errors
+        .iter()
+        .map(|(host, error)| format!("{}: {}", host, error))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .into()
+}
+
+async fn fetch_activity(
+    date: &str,
+    cache: Option<&DayCache<'_>>,
+    filter: &RepoFilter,
+) -> Result<GitHubActivityResponse, GitHubError> {
+    let activity = fetch_all_hosts(DateRange::day(date), cache, filter).await?;
+
+    Ok(GitHubActivityResponse {
+        login: activity.login,
+        date: date.to_string(),
+        items: activity.items,
+        hosts: activity.hosts,
+    })
+}
+
+/// Fetch one host's activity. `cache` applies to single-day ranges only.
+/// Results may include repositories outside `filter`; callers post-filter.
+async fn fetch_host_activity(
+    host: &GitHubHost,
+    range: DateRange<'_>,
+    cache: Option<&DayCache<'_>>,
+    filter: &RepoFilter,
+) -> Result<HostActivity, GitHubError> {
+    let known_login = host.login.clone().or_else(|| cached_login(&host.host));
+    let cache = cache.filter(|_| range.from == range.to);
+    let date = range.from;
+
+    if let Some(cache) = cache.filter(|cache| cache.read) {
+        if let Some(cached) = read_cached_activity(cache.dir, &host.host, date) {
+            // A different login means the token changed since the day was cached
+            if known_login
+                .as_ref()
+                .map(|login| *login == cached.login)
+                .unwrap_or(true)
+            {
+                return Ok(HostActivity {
+                    login: cached.login,
+                    items: cached.items,
+                    incomplete: cached.incomplete,
+                });
+            }
+        }
+    }
+
+    let client = GitHubClient::for_host(host).await?;
+    let login = host_login(&client, host).await?;
+
+    // Cached days hold the unfiltered activity so any filter can be applied later
+    let scope = if cache.is_some() {
+        String::new()
+    } else {
+        filter.qualifiers()
+    };
+    let scope = scope.as_str();
+
+    let (searched, events) = futures_util::join!(
+        async {
+            futures_util::try_join!(
+                fetch_commits(&client, &login, range, scope),
+                fetch_pull_requests(&client, &login, range, scope),
+                fetch_merged(&client, &login, range, scope),
+                fetch_reviews(&client, &login, range, scope),
+                fetch_comments(&client, &login, range, scope),
+                fetch_issues_opened(&client, &login, range, scope),
+            )
+        },
+        fetch_events(&client, &login, range),
+    );
+    let (commits, pull_requests, merged, reviews, comments, issues) = searched?;
+
+    // The events feed only adds closed issues and releases, so it may fail alone
+    let events_failed = events.is_err();
+    let (events, incomplete) = match events {
+        Ok((events, true)) => (events, None),
+        Ok((events, false)) => (events, Some(EVENTS_WINDOW_NOTE.to_string())),
+        Err(error) => (
+            Vec::new(),
+            Some(format!(
+                "Closed issues and releases could not be loaded: {}",
+                error
+            )),
+        ),
+    };
+    // A failed feed is retried next time instead of being cached
+    let cache = cache.filter(|_| !events_failed);
+
+    let mut items = commits;
+    items.extend(pull_requests);
+    items.extend(merged);
+    items.extend(reviews);
+    items.extend(comments);
+    items.extend(issues);
+    items.extend(events);
+
+    if let Some(cache) = cache {
+        let activity = CachedHostActivity::new(login, items, incomplete);
+        write_cached_activity(cache.dir, &host.host, date, &activity);
+        return Ok(HostActivity {
+            login: activity.login,
+            items: activity.items,
+            incomplete: activity.incomplete,
+        });
+    }
+
+    Ok(HostActivity {
+        login,
+        items,
+        incomplete,
+    })
+}
+
+/// Login configured for `host`, or the one its token belongs to (cached for
+/// the session).
+async fn host_login(client: &GitHubClient, host: &GitHubHost) -> Result<String, GitHubError> {
+    let login = match host.login.clone().or_else(|| cached_login(&host.host)) {
+        Some(login) => login,
+        None => {
+            let login = fetch_login(client).await?;
+            cache_login(&host.host, &login);
+            login
+        }
+    };
+    validate_login(&login)?;
+    Ok(login)
+}
+
+#[derive(Debug, Deserialize)]
+struct UserInfo {
+    login: String,
+}
+
+async fn fetch_login(client: &GitHubClient) -> Result<String, GitHubError> {
+    let user: UserInfo = client.get("user", &[], None).await?;
+    let login = user.login.trim().to_string();
+    if login.is_empty() {
+        return Err("GitHub login not available. Run `gh auth login`.".into());
+    }
+    Ok(login)
+}
+
+async fn fetch_commits(
+    client: &GitHubClient,
+    login: &str,
+    range: DateRange<'_>,
+    scope: &str,
+) -> Result<Vec<GitHubActivityItem>, GitHubError> {
+    let query = format!(
+        "author:{} author-date:{}{}",
+        login,
+        range.qualifier(),
+        scope
+    );
+    let items = client
+        .get_pages::<SearchResponse<SearchCommitItem>, _>(
+            "search/commits",
+            &[
+                ("q", query.as_str()),
+                ("sort", "author-date"),
+                ("order", "desc"),
+                ("per_page", "100"),
+            ],
+            Some("application/vnd.github.cloak-preview+json"),
+        )
+        .await?;
+
+    Ok(stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| commit_item(client, item, range, index < MAX_ENRICHED_COMMITS))
+        .buffered(DETAIL_CONCURRENCY)
+        .collect()
+        .await)
+}
+
+/// Activity item of a searched commit. With `enrich`, its diffstat and pull
+/// request are looked up; a failed lookup leaves them unset.
+async fn commit_item(
+    client: &GitHubClient,
+    item: SearchCommitItem,
+    range: DateRange<'_>,
+    enrich: bool,
+) -> GitHubActivityItem {
+    let repo = item.repository.full_name;
+    let (stats, pull_requests) = if enrich {
+        let (stats, pull_requests) = futures_util::join!(
+            client.get::<CommitStatsResponse>(
+                &format!("repos/{}/commits/{}", repo, item.sha),
+                &[],
+                None
+            ),
+            client.get::<Vec<CommitPullRequest>>(
+                &format!("repos/{}/commits/{}/pulls", repo, item.sha),
+                &[],
+                None
+            ),
+        );
+        (stats.ok(), pull_requests.ok())
+    } else {
+        (None, None)
+    };
+
+    let title = item
+        .commit
+        .message
+        .lines()
+        .next()
+        .unwrap_or("Commit")
+        .trim()
+        .to_string();
+    let timestamp = item
+        .commit
+        .author
+        .as_ref()
+        .and_then(|author| author.date.clone())
+        .or_else(|| {
+            item.commit
+                .committer
+                .as_ref()
+                .and_then(|committer| committer.date.clone())
+        })
+        .unwrap_or_else(|| range.from.to_string());
+    let summary = item.sha.chars().take(7).collect::<String>();
+
+    let pull_request = pull_requests.and_then(|pull_requests| pull_requests.into_iter().next());
+    let mut details = GitHubCommitDetails::new(
+        item.sha,
+        &item.commit.message,
+        pull_request.as_ref().map(|pr| pr.head.ref_name.clone()),
+        pull_request.map(|pr| pr.title),
+    );
+    if let Some(stats) = stats {
+        details.files_changed =
+            (stats.files.len() < MAX_COMMIT_FILES).then_some(stats.files.len() as u32);
+        if let Some(totals) = stats.stats {
+            details.additions = Some(totals.additions);
+            details.deletions = Some(totals.deletions);
+        }
+    }
+
+    GitHubActivityItem {
+        kind: GitHubActivityKind::Commit,
+        title: clean_title(title, "Commit"),
+        url: item.html_url,
+        repo,
+        host: client.host().to_string(),
+        timestamp,
+        number: None,
+        summary: Some(summary),
+        pr_state: None,
+        commit: Some(details),
+    }
+}
+
+/// Message lines after the subject, or `None` when there are none.
+fn commit_body(message: &str) -> Option<String> {
+    let (_, body) = message.split_once('\n')?;
+    let body = body.trim();
+    (!body.is_empty()).then(|| body.to_string())
+}
+
+async fn fetch_pull_requests(
+    client: &GitHubClient,
+    login: &str,
+    range: DateRange<'_>,
+    scope: &str,
+) -> Result<Vec<GitHubActivityItem>, GitHubError> {
+    let query = format!(
+        "is:pr author:{} created:{}{}",
+        login,
+        range.qualifier(),
+        scope
+    );
+    let items = search_issues(client, &query).await?;
+
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            let pr_state = item.pull_request_state();
+            GitHubActivityItem {
+                kind: GitHubActivityKind::PullRequest,
+                title: clean_title(item.title, "Untitled pull request"),
+                url: item.html_url,
+                repo: repo_from_api_url(&item.repository_url),
+                host: client.host().to_string(),
+                timestamp: item.created_at,
+                number: Some(item.number),
+                summary: Some(format!("#{}", item.number)),
+                pr_state,
+                commit: None,
+            }
+        })
+        .collect())
+}
+
+async fn fetch_merged(
+    client: &GitHubClient,
+    login: &str,
+    range: DateRange<'_>,
+    scope: &str,
+) -> Result<Vec<GitHubActivityItem>, GitHubError> {
+    let query = format!(
+        "is:pr author:{} merged:{}{}",
+        login,
+        range.qualifier(),
+        scope
+    );
+    let items = search_issues(client, &query).await?;
+
+    Ok(items
+        .into_iter()
+        .filter_map(|item| {
+            let merged_at = item.merged_at()?.to_string();
+            Some(GitHubActivityItem {
+                kind: GitHubActivityKind::Merged,
+                title: clean_title(item.title, "Untitled pull request"),
+                url: item.html_url,
+                repo: repo_from_api_url(&item.repository_url),
+                host: client.host().to_string(),
+                timestamp: merged_at,
+                number: Some(item.number),
+                summary: Some(format!("#{}", item.number)),
+                pr_state: Some(GitHubPullRequestState::Merged),
+                commit: None,
+            })
+        })
+        .collect())
+}
+
+async fn fetch_issues_opened(
+    client: &GitHubClient,
+    login: &str,
+    range: DateRange<'_>,
+    scope: &str,
+) -> Result<Vec<GitHubActivityItem>, GitHubError> {
+    let query = format!(
+        "is:issue author:{} created:{}{}",
+        login,
+        range.qualifier(),
+        scope
+    );
+    let items = search_issues(client, &query).await?;
+
+    Ok(items
+        .into_iter()
+        .map(|item| GitHubActivityItem {
+            kind: GitHubActivityKind::IssueOpened,
+            title: clean_title(item.title, "Untitled issue"),
+            url: item.html_url,
+            repo: repo_from_api_url(&item.repository_url),
+            host: client.host().to_string(),
+            timestamp: item.created_at,
+            number: Some(item.number),
+            summary: Some(format!("#{}", item.number)),
+            pr_state: None,
+            commit: None,
+        })
+        .collect())
+}
+
+/// Whether `events` (newest first) reach back to the start of `range`.
+fn events_cover(events: &[UserEvent], range: DateRange<'_>) -> bool {
+    let reaches_before = events
+        .last()
+        .and_then(|event| kst_date_of(&event.created_at))
+        .is_some_and(|oldest| oldest.as_str() < range.from);
+    if reaches_before {
+        return true;
+    }
+    // Fewer events than the cap means everything in the window was returned
+    events.len() < MAX_EVENTS
+        && day_span(range.from, &kst_today()).is_some_and(|days| days <= EVENTS_WINDOW_DAYS)
+}
+
+/// Issues closed and releases published by `login`. Search cannot tell who
+/// closed an issue, so these come from the user's events, which only reach
+/// back 90 days (and at most 300 events). Also returns whether the events
+/// cover all of `range`.
+async fn fetch_events(
+    client: &GitHubClient,
+    login: &str,
+    range: DateRange<'_>,
+) -> Result<(Vec<GitHubActivityItem>, bool), GitHubError> {
+    let path = format!("users/{}/events", login);
+    let events = client
+        .get_pages::<Vec<UserEvent>, _>(&path, &[("per_page", "100")], None)
+        .await?;
+    let complete = events_cover(&events, range);
+
+    let items = events
+        .into_iter()
+        .filter(|event| range.contains(&event.created_at))
+        .filter_map(|event| match event.event_type.as_str() {
+            "IssuesEvent" => {
+                let payload: IssuesEventPayload = serde_json::from_value(event.payload).ok()?;
+                if payload.action != "closed" {
+                    return None;
+                }
+                let issue = payload.issue;
+                Some(GitHubActivityItem {
+                    kind: GitHubActivityKind::IssueClosed,
+                    title: clean_title(issue.title, "Untitled issue"),
+                    url: issue.html_url,
+                    repo: event.repo.name,
+                    host: client.host().to_string(),
+                    timestamp: event.created_at,
+                    number: Some(issue.number),
+                    summary: Some(format!("#{}", issue.number)),
+                    pr_state: None,
+                    commit: None,
+                })
+            }
+            "ReleaseEvent" => {
+                let payload: ReleaseEventPayload = serde_json::from_value(event.payload).ok()?;
+                if payload.action != "published" {
+                    return None;
+                }
+                let release = payload.release;
+                let title = release.name.unwrap_or_else(|| release.tag_name.clone());
+                Some(GitHubActivityItem {
+                    kind: GitHubActivityKind::Released,
+                    title: clean_title(title, &release.tag_name),
+                    url: release.html_url,
+                    repo: event.repo.name,
+                    host: client.host().to_string(),
+                    timestamp: event.created_at,
+                    number: None,
+                    summary: Some(release.tag_name),
+                    pr_state: None,
+                    commit: None,
+                })
+            }
+            _ => None,
+        })
+        .collect();
+
+    Ok((items, complete))
+}
+
+async fn search_issues(
+    client: &GitHubClient,
+    query: &str,
+) -> Result<Vec<SearchIssueItem>, GitHubError> {
+    client
+        .get_pages::<SearchResponse<SearchIssueItem>, _>(
+            "search/issues",
+            &[("q", query), ("per_page", "100")],
+            None,
+        )
+        .await
+}
+
+/// Search qualifier for issues updated during `range` or within
+/// `UPDATED_WINDOW_DAYS` after it. `updated` moves with any later activity, so
+/// the lower bound alone would match every issue touched since, and an older
+/// range would fan out to ever more detail requests.
+fn updated_within(range: DateRange<'_>) -> Result<String, GitHubError> {
+    let to = add_days(range.to, UPDATED_WINDOW_DAYS).ok_or("Date must be in YYYY-MM-DD format")?;
+    Ok(format!(
+        "updated:{}T00:00:00+09:00..{}T23:59:59+09:00",
+        range.from, to
+    ))
+}
+
+fn is_author(user: &Option<UserRef>, login: &str) -> bool {
+    user.as_ref()
+        .map(|user| user.login.eq_ignore_ascii_case(login))
+        .unwrap_or(false)
+}
+
+/// First line of `body` with Markdown whitespace collapsed, cut at `EXCERPT_CHARS`.
+fn excerpt(body: Option<&str>) -> Option<String> {
+    let text = body?.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    Some(match text.char_indices().nth(EXCERPT_CHARS) {
+        Some((index, _)) => format!("{}…", &text[..index]),
+        None => text,
+    })
+}
+
+fn review_state_label(state: &str) -> &'static str {
+    match state {
+        "APPROVED" => "Approved",
+        "CHANGES_REQUESTED" => "Changes requested",
+        "DISMISSED" => "Dismissed",
+        _ => "Commented",
+    }
+}
+
+async fn fetch_reviews(
+    client: &GitHubClient,
+    login: &str,
+    range: DateRange<'_>,
+    scope: &str,
+) -> Result<Vec<GitHubActivityItem>, GitHubError> {
+    // The search only narrows down candidate PRs; the reviews themselves are
+    // checked below.
+    let query = format!(
+        "is:pr reviewed-by:{} {}{}",
+        login,
+        updated_within(range)?,
+        scope
+    );
+    let pull_requests = search_issues(client, &query).await?;
+
+    let since = kst_day_start_utc(range.from).ok_or("Date must be in YYYY-MM-DD format")?;
+    let since = since.as_str();
+
+    let items: Vec<Vec<GitHubActivityItem>> = stream::iter(pull_requests)
+        .map(|pr| async move {
+            let repo = repo_from_api_url(&pr.repository_url);
+            let reviews_path = format!("repos/{}/pulls/{}/reviews", repo, pr.number);
+            // Comments on the diff belong to a review but are not part of its body
+            let comments_path = format!("repos/{}/pulls/{}/comments", repo, pr.number);
+            let (reviews, comments) = futures_util::try_join!(
+                client.get_pages::<Vec<ReviewItem>, _>(&reviews_path, &[("per_page", "100")], None),
+                client.get_pages::<Vec<CommentItem>, _>(
+                    &comments_path,
+                    &[("since", since), ("per_page", "100")],
+                    None
+                ),
+            )?;
+            let comments: Vec<CommentItem> = comments
+                .into_iter()
+                .filter(|comment| is_author(&comment.user, login))
+                .filter(|comment| range.contains(&comment.created_at))
+                .collect();
+
+            let pr_state = pr.pull_request_state();
+            let title = clean_title(pr.title, "Untitled review");
+            let review_comments: Vec<GitHubActivityItem> = comments
+                .into_iter()
+                .map(|comment| {
+                    let summary = match excerpt(comment.body.as_deref()) {
+                        Some(body) => format!("Review comment · #{} · {}", pr.number, body),
+                        None => format!("Review comment · #{}", pr.number),
+                    };
+                    GitHubActivityItem {
+                        kind: GitHubActivityKind::Comment,
+                        title: title.clone(),
+                        url: comment.html_url,
+                        repo: repo.clone(),
+                        host: client.host().to_string(),
+                        timestamp: comment.created_at,
+                        number: Some(pr.number),
+                        summary: Some(summary),
+                        pr_state,
+                        commit: None,
+                    }
+                })
+                .collect();
+            let has_review_comments = !review_comments.is_empty();
+
+            let items: Vec<GitHubActivityItem> = reviews
+                .into_iter()
+                .filter(|review| is_author(&review.user, login))
+                .filter_map(|review| {
+                    let submitted_at = review.submitted_at?;
+                    if !range.contains(&submitted_at) {
+                        return None;
+                    }
+                    let body = excerpt(review.body.as_deref());
+                    // A comment review without a body only wraps the diff comments above
+                    if review.state == "COMMENTED" && body.is_none() && has_review_comments {
+                        return None;
+                    }
+                    let state = review_state_label(&review.state);
+                    let summary = match body {
+                        Some(body) => format!("{} · #{} · {}", state, pr.number, body),
+                        None => format!("{} · #{}", state, pr.number),
+                    };
+                    Some(GitHubActivityItem {
+                        kind: GitHubActivityKind::Review,
+                        title: title.clone(),
+                        url: review.html_url,
+                        repo: repo.clone(),
+                        host: client.host().to_string(),
+                        timestamp: submitted_at,
+                        number: Some(pr.number),
+                        summary: Some(summary),
+                        pr_state,
+                        commit: None,
+                    })
+                })
+                .chain(review_comments)
+                .collect();
+            Ok::<_, GitHubError>(items)
+        })
+        .buffered(DETAIL_CONCURRENCY)
+        .try_collect()
+        .await?;
+
+    Ok(items.into_iter().flatten().collect())
+}
+
+async fn fetch_comments(
+    client: &GitHubClient,
+    login: &str,
+    range: DateRange<'_>,
+    scope: &str,
+) -> Result<Vec<GitHubActivityItem>, GitHubError> {
+    let query = format!("commenter:{} {}{}", login, updated_within(range)?, scope);
+    let issues = search_issues(client, &query).await?;
+
+    let since = kst_day_start_utc(range.from).ok_or("Date must be in YYYY-MM-DD format")?;
+    let since = since.as_str();
+
+    let items: Vec<Vec<GitHubActivityItem>> = stream::iter(issues)
+        .map(|issue| async move {
+            let repo = repo_from_api_url(&issue.repository_url);
+            let path = format!("repos/{}/issues/{}/comments", repo, issue.number);
+            let comments = client
+                .get_pages::<Vec<CommentItem>, _>(
+                    &path,
+                    &[("since", since), ("per_page", "100")],
+                    None,
+                )
+                .await?;
+
+            let pr_state = issue.pull_request_state();
+            let label = if issue.pull_request.is_some() {
+                "PR"
+            } else {
+                "Issue"
+            };
+            let title = clean_title(issue.title, "Untitled comment");
+            Ok::<_, GitHubError>(
+                comments
+                    .into_iter()
+                    .filter(|comment| is_author(&comment.user, login))
+                    .filter(|comment| range.contains(&comment.created_at))
+                    .map(|comment| {
+                        let summary = match excerpt(comment.body.as_deref()) {
+                            Some(body) => format!("{} #{} · {}", label, issue.number, body),
+                            None => format!("{} #{}", label, issue.number),
+                        };
+                        GitHubActivityItem {
+                            kind: GitHubActivityKind::Comment,
+                            title: title.clone(),
+                            url: comment.html_url,
+                            repo: repo.clone(),
+                            host: client.host().to_string(),
+                            timestamp: comment.created_at,
+                            number: Some(issue.number),
+                            summary: Some(summary),
+                            pr_state,
+                            commit: None,
+                        }
+                    })
+                    .collect(),
+            )
+        })
+        .buffered(DETAIL_CONCURRENCY)
+        .try_collect()
+        .await?;
+
+    Ok(items.into_iter().flatten().collect())
+}
+
+fn clean_title(value: String, fallback: &str) -> String {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        fallback.to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn repo_from_api_url(url: &str) -> String {
+    if let Some(repo) = url.split("/repos/").nth(1) {
+        return repo.to_string();
+    }
+    url.to_string()
+}
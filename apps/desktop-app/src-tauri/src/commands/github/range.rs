@@ -0,0 +1,283 @@
+use super::dates::{day_span, kst_date_of};
+use super::{
+    fetch_all_hosts, validate_date, DateRange, GitHubActivityItem, GitHubActivityKind, GitHubError,
+    GitHubHostStatus, GitHubRepoFilter, RepoFilter,
+};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+
+/// Longest range accepted, enough for a quarter.
+const MAX_RANGE_DAYS: i64 = 92;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Type)]
+pub struct GitHubActivityCounts {
+    pub commits: u32,
+    pub pull_requests_opened: u32,
+    pub pull_requests_merged: u32,
+    pub reviews: u32,
+    pub comments: u32,
+    pub issues_opened: u32,
+    pub issues_closed: u32,
+    pub releases: u32,
+}
+
+impl GitHubActivityCounts {
+    fn add_item(&mut self, kind: &GitHubActivityKind) {
+        match kind {
+            GitHubActivityKind::Commit => self.commits += 1,
+            GitHubActivityKind::PullRequest => self.pull_requests_opened += 1,
+            GitHubActivityKind::Review => self.reviews += 1,
+            GitHubActivityKind::Comment => self.comments += 1,
+            GitHubActivityKind::Merged => self.pull_requests_merged += 1,
+            GitHubActivityKind::IssueOpened => self.issues_opened += 1,
+            GitHubActivityKind::IssueClosed => self.issues_closed += 1,
+            GitHubActivityKind::Released => self.releases += 1,
+        }
+    }
+
+    fn add(&mut self, other: &GitHubActivityCounts) {
+        self.commits += other.commits;
+        self.pull_requests_opened += other.pull_requests_opened;
+        self.pull_requests_merged += other.pull_requests_merged;
+        self.reviews += other.reviews;
+        self.comments += other.comments;
+        self.issues_opened += other.issues_opened;
+        self.issues_closed += other.issues_closed;
+        self.releases += other.releases;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubRepoActivity {
+    pub host: String,
+    pub repo: String,
+    pub counts: GitHubActivityCounts,
+    pub items: Vec<GitHubActivityItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubDayActivity {
+    pub date: String,
+    pub counts: GitHubActivityCounts,
+    pub repos: Vec<GitHubRepoActivity>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct GitHubActivityRangeResponse {
+    pub login: String,
+    pub from: String,
+    pub to: String,
+    pub totals: GitHubActivityCounts,
+    /// Days with activity, oldest first.
+    pub days: Vec<GitHubDayActivity>,
+    pub hosts: Vec<GitHubHostStatus>,
+}
+
+/// date -> (host, repo) -> activity, both in ascending order.
+type Grouped = BTreeMap<String, BTreeMap<(String, String), GitHubRepoActivity>>;
+
+fn repo_activity<'a>(
+    grouped: &'a mut Grouped,
+    date: String,
+    host: &str,
+    repo: &str,
+) -> &'a mut GitHubRepoActivity {
+    grouped
+        .entry(date)
+        .or_default()
+        .entry((host.to_string(), repo.to_string()))
+        .or_insert_with(|| GitHubRepoActivity {
+            host: host.to_string(),
+            repo: repo.to_string(),
+            counts: GitHubActivityCounts::default(),
+            items: Vec::new(),
+        })
+}
+
+/// Number of days in `from..=to`, which must be in order and at most
+/// `MAX_RANGE_DAYS` long.
+fn range_days(from: &str, to: &str) -> Result<i64, GitHubError> {
+    let span = day_span(from, to).ok_or("Date must be in YYYY-MM-DD format")?;
+    if span < 1 {
+        return Err("Start date must not be after end date".into());
+    }
+    if span > MAX_RANGE_DAYS {
+        return Err(format!("Date range must not exceed {} days", MAX_RANGE_DAYS).into());
+    }
+    Ok(span)
+}
+
+/// Items grouped by KST day and then by repository, with counts per repo,
+/// per day and in total. Items without a readable timestamp go on `from`.
+fn group_by_day(
+    items: Vec<GitHubActivityItem>,
+    from: &str,
+) -> (Vec<GitHubDayActivity>, GitHubActivityCounts) {
+    let mut grouped = Grouped::new();
+    for item in items {
+        let date = kst_date_of(&item.timestamp).unwrap_or_else(|| from.to_string());
+        let entry = repo_activity(&mut grouped, date, &item.host, &item.repo);
+        entry.counts.add_item(&item.kind);
+        entry.items.push(item);
+    }
+
+    let mut totals = GitHubActivityCounts::default();
+    let days = grouped
+        .into_iter()
+        .map(|(date, repos)| {
+            let mut counts = GitHubActivityCounts::default();
+            let repos: Vec<GitHubRepoActivity> = repos.into_values().collect();
+            for repo in &repos {
+                counts.add(&repo.counts);
+            }
+            totals.add(&counts);
+            GitHubDayActivity {
+                date,
+                counts,
+                repos,
+            }
+        })
+        .collect();
+
+    (days, totals)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_github_activity_range(
+    from: String,
+    to: String,
+    filter: Option<GitHubRepoFilter>,
+) -> Result<GitHubActivityRangeResponse, GitHubError> {
+    let from = from.trim().to_string();
+    let to = to.trim().to_string();
+    validate_date(&from)?;
+    validate_date(&to)?;
+    let filter = RepoFilter::new(&filter.unwrap_or_default())?;
+
+    range_days(&from, &to)?;
+
+    let range = DateRange {
+        from: &from,
+        to: &to,
+    };
+    let activity = fetch_all_hosts(range, None, &filter).await?;
+    let (days, totals) = group_by_day(activity.items, &from);
+
+    Ok(GitHubActivityRangeResponse {
+        login: activity.login,
+        from,
+        to,
+        totals,
+        days,
+        hosts: activity.hosts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(
+        kind: GitHubActivityKind,
+        host: &str,
+        repo: &str,
+        timestamp: &str,
+    ) -> GitHubActivityItem {
+        GitHubActivityItem {
+            kind,
+            title: "title".to_string(),
+            url: String::new(),
+            repo: repo.to_string(),
+            host: host.to_string(),
+            timestamp: timestamp.to_string(),
+            number: None,
+            summary: None,
+            pr_state: None,
+            commit: None,
+        }
+    }
+
+    #[test]
+    fn limits_range_length_and_order() {
+        assert_eq!(range_days("2026-01-01", "2026-01-01").ok(), Some(1));
+        // 2026-01-01 through 2026-04-02 is 92 days
+        assert_eq!(range_days("2026-01-01", "2026-04-02").ok(), Some(92));
+        assert!(range_days("2026-01-01", "2026-04-03").is_err());
+        assert!(range_days("2026-01-02", "2026-01-01").is_err());
+    }
+
+    #[test]
+    fn counts_per_day_and_repo() {
+        let (days, totals) = group_by_day(
+            vec![
+                // 23:30 UTC on the 21st is the 22nd in KST
+                item(
+                    GitHubActivityKind::Commit,
+                    "github.com",
+                    "acme/api",
+                    "2026-01-21T23:30:00Z",
+                ),
+                item(
+                    GitHubActivityKind::Commit,
+                    "github.com",
+                    "acme/api",
+                    "2026-01-22T01:00:00Z",
+                ),
+                item(
+                    GitHubActivityKind::Review,
+                    "github.com",
+                    "acme/web",
+                    "2026-01-22T02:00:00Z",
+                ),
+                item(
+                    GitHubActivityKind::Merged,
+                    "git.example.com",
+                    "acme/api",
+                    "2026-01-22T03:00:00Z",
+                ),
+                item(
+                    GitHubActivityKind::PullRequest,
+                    "github.com",
+                    "acme/api",
+                    "2026-01-21T10:00:00Z",
+                ),
+            ],
+            "2026-01-21",
+        );
+
+        let dates: Vec<&str> = days.iter().map(|day| day.date.as_str()).collect();
+        assert_eq!(dates, ["2026-01-21", "2026-01-22"]);
+        assert_eq!(days[0].counts.pull_requests_opened, 1);
+
+        let day = &days[1];
+        assert_eq!(day.counts.commits, 2);
+        assert_eq!(day.counts.reviews, 1);
+        assert_eq!(day.counts.pull_requests_merged, 1);
+        let repos: Vec<(&str, &str, u32)> = day
+            .repos
+            .iter()
+            .map(|repo| {
+                (
+                    repo.host.as_str(),
+                    repo.repo.as_str(),
+                    repo.items.len() as u32,
+                )
+            })
+            .collect();
+        assert_eq!(
+            repos,
+            [
+                ("git.example.com", "acme/api", 1),
+                ("github.com", "acme/api", 2),
+                ("github.com", "acme/web", 1),
+            ]
+        );
+
+        assert_eq!(totals.commits, 2);
+        assert_eq!(totals.pull_requests_opened, 1);
+        assert_eq!(totals.reviews, 1);
+        assert_eq!(totals.pull_requests_merged, 1);
+    }
+}
@@ -0,0 +1,146 @@
+use super::{
+    fetch_day_activity, get_local_git_activity, GitHubActivityItem, GitHubRepoFilter, RepoFilter,
+};
+use crate::commands::activity::{
+    ActivityItem, ActivitySource, ActivitySourceKind, ActivitySourceSettings,
+};
+use futures_util::future::{BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::AppHandle;
+
+impl GitHubActivityItem {
+    fn into_activity_item(self, source: ActivitySourceKind) -> ActivityItem {
+        // Keep the Jira keys of a commit, which have no field of their own here
+        let jira_keys = self
+            .commit
+            .as_ref()
+            .filter(|commit| !commit.jira_keys.is_empty())
+            .map(|commit| commit.jira_keys.join(", "));
+        let sha = self.commit.as_ref().map(|commit| commit.sha.clone());
+        let summary = match (self.summary, jira_keys) {
+            (Some(summary), Some(keys)) => Some(format!("{} · {}", summary, keys)),
+            (summary, keys) => summary.or(keys),
+        };
+
+        ActivityItem {
+            source,
+            kind: self.kind.as_str().to_string(),
+            title: self.title,
+            // Local repositories without a remote have no URL
+            url: Some(self.url).filter(|url| !url.is_empty()),
+            timestamp: self.timestamp,
+            project: Some(self.repo),
+            summary,
+            sha,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GitHubSourceSettings {
+    pub filter: Option<GitHubRepoFilter>,
+}
+
+impl ActivitySourceSettings for GitHubSourceSettings {
+    fn into_source(self, app: &AppHandle) -> Result<Box<dyn ActivitySource>, String> {
+        Ok(Box::new(GitHubActivitySource::new(
+            app.clone(),
+            &self.filter.unwrap_or_default(),
+        )?))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LocalGitSourceSettings {
+    pub repo_roots: Vec<String>,
+    pub author_emails: Vec<String>,
+}
+
+impl ActivitySourceSettings for LocalGitSourceSettings {
+    fn into_source(self, _app: &AppHandle) -> Result<Box<dyn ActivitySource>, String> {
+        Ok(Box::new(LocalGitActivitySource::new(
+            self.repo_roots,
+            self.author_emails,
+        )))
+    }
+}
+
+/// Activity across all configured GitHub hosts.
+struct GitHubActivitySource {
+    app: AppHandle,
+    filter: RepoFilter,
+}
+
+impl GitHubActivitySource {
+    pub fn new(app: AppHandle, filter: &GitHubRepoFilter) -> Result<Self, String> {
+        Ok(Self {
+            app,
+            filter: RepoFilter::new(filter)?,
+        })
+    }
+}
+
+impl ActivitySource for GitHubActivitySource {
+    fn kind(&self) -> ActivitySourceKind {
+        ActivitySourceKind::Github
+    }
+
+    fn daily_activity<'a>(
+        &'a self,
+        date: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<ActivityItem>, String>> {
+        async move {
+            let response = fetch_day_activity(&self.app, date, false, &self.filter)
+                .await
+                .map_err(|error| error.to_string())?;
+            Ok(response
+                .items
+                .into_iter()
+                .map(|item| item.into_activity_item(ActivitySourceKind::Github))
+                .collect())
+        }
+        .boxed()
+    }
+}
+
+/// Commits in local git repositories.
+struct LocalGitActivitySource {
+    repo_roots: Vec<String>,
+    author_emails: Vec<String>,
+}
+
+impl LocalGitActivitySource {
+    pub fn new(repo_roots: Vec<String>, author_emails: Vec<String>) -> Self {
+        Self {
+            repo_roots,
+            author_emails,
+        }
+    }
+}
+
+impl ActivitySource for LocalGitActivitySource {
+    fn kind(&self) -> ActivitySourceKind {
+        ActivitySourceKind::LocalGit
+    }
+
+    fn daily_activity<'a>(
+        &'a self,
+        date: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<ActivityItem>, String>> {
+        async move {
+            let response = get_local_git_activity(
+                date.to_string(),
+                self.repo_roots.clone(),
+                self.author_emails.clone(),
+            )
+            .await?;
+            Ok(response
+                .items
+                .into_iter()
+                .map(|item| item.into_activity_item(ActivitySourceKind::LocalGit))
+                .collect())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,317 @@
+use reqwest::header::{ACCEPT, USER_AGENT};
+use reqwest::{Method, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::{JiraIssue, JiraUserProfile};
+use crate::commands::secure::read_jira_token;
+
+const JIRA_USER_AGENT: &str = "en-ttokk";
+/// Error for a 401 response, i.e. a wrong email or a revoked or expired token.
+pub(super) const UNAUTHORIZED_ERROR: &str = "Jira rejected the email or API token";
+const REQUEST_TIMEOUT_SECS: u64 = 8;
+/// Fields requested for every issue.
+const ISSUE_FIELDS: [&str; 6] = [
+    "summary",
+    "status",
+    "assignee",
+    "updated",
+    "issuetype",
+    "priority",
+];
+
+#[derive(Debug, Deserialize)]
+struct ApiError {
+    #[serde(default, rename = "errorMessages")]
+    error_messages: Vec<String>,
+    #[serde(default)]
+    errors: HashMap<String, String>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Myself {
+    display_name: Option<String>,
+    email_address: Option<String>,
+    account_id: Option<String>,
+    avatar_urls: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    issues: Vec<IssueResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueResponse {
+    key: String,
+    fields: Option<IssueFields>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueFields {
+    summary: Option<String>,
+    status: Option<Named>,
+    assignee: Option<Person>,
+    updated: Option<String>,
+    issuetype: Option<Named>,
+    priority: Option<Named>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Named {
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Person {
+    display_name: Option<String>,
+}
+
+/// Client for the Jira Cloud REST API, authenticated with an email and API token.
+pub(super) struct JiraClient {
+    http: reqwest::Client,
+    base_url: String,
+    email: String,
+    token: String,
+}
+
+impl JiraClient {
+    /// `base_url` is the site root, e.g. "https://example.atlassian.net".
+    pub fn new(base_url: &str, email: &str, token: &str) -> Result<Self, String> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            // Never send the credentials on to another host
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| format!("Failed to create Jira client: {}", e))?;
+
+        Ok(Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            email: email.to_string(),
+            token: token.to_string(),
+        })
+    }
+
+    /// Client with the saved API token, which never leaves the backend.
+    pub fn with_saved_token(base_url: &str, email: &str) -> Result<Self, String> {
+        let token = read_jira_token()?
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+            .ok_or("Jira API token is not set")?;
+        Self::new(base_url, email, &token)
+    }
+
+    pub fn issue_url(&self, key: &str) -> String {
+        format!("{}/browse/{}", self.base_url, key)
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<T, String> {
+        let url = Url::parse(&format!("{}/{}", self.base_url, path))
+            .map_err(|e| format!("Invalid Jira URL: {}", e))?;
+        let mut request = self
+            .http
+            .request(method, url)
+            .basic_auth(&self.email, Some(&self.token))
+            .header(ACCEPT, "application/json")
+            .header(USER_AGENT, JIRA_USER_AGENT);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                "Jira request timed out".to_string()
+            } else {
+                "Unable to reach Jira API".to_string()
+            }
+        })?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read Jira response: {}", e))?;
+
+        if status.is_redirection() {
+            return Err("Jira redirected the request. Check the site URL.".to_string());
+        }
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(UNAUTHORIZED_ERROR.to_string());
+        }
+        if !status.is_success() {
+            return Err(api_error(status, &body));
+        }
+        serde_json::from_str(&body).map_err(|e| format!("Unexpected Jira response: {}", e))
+    }
+
+    /// Profile of the account the token belongs to.
+    pub async fn myself(&self) -> Result<JiraUserProfile, String> {
+        let myself: Myself = self.send(Method::GET, "rest/api/3/myself", None).await?;
+        Ok(JiraUserProfile {
+            display_name: myself.display_name.unwrap_or_else(|| "Unknown".to_string()),
+            email_address: myself.email_address,
+            account_id: myself.account_id,
+            avatar_urls: myself.avatar_urls,
+        })
+    }
+
+    /// Issues matching `jql`, in the order JQL sorts them.
+    pub async fn search(&self, jql: &str, max_results: u32) -> Result<Vec<JiraIssue>, String> {
+        let body = serde_json::json!({
+            "jql": jql,
+            "maxResults": max_results,
+            "fields": ISSUE_FIELDS,
+        });
+        let response: SearchResponse = self
+            .send(Method::POST, "rest/api/3/search/jql", Some(body))
+            .await?;
+        Ok(response
+            .issues
+            .into_iter()
+            .map(|issue| self.to_issue(issue))
+            .collect())
+    }
+
+    pub async fn issue(&self, key: &str) -> Result<JiraIssue, String> {
+        let path = format!("rest/api/3/issue/{}?fields={}", key, ISSUE_FIELDS.join(","));
+        let issue: IssueResponse = self.send(Method::GET, &path, None).await?;
+        Ok(self.to_issue(issue))
+    }
+
+    fn to_issue(&self, issue: IssueResponse) -> JiraIssue {
+        let fields = issue.fields.unwrap_or_default();
+        let name = |named: Option<Named>| named.and_then(|named| named.name);
+
+        JiraIssue {
+            url: self.issue_url(&issue.key),
+            key: issue.key,
+            summary: fields
+                .summary
+                .map(|summary| summary.trim().to_string())
+                .filter(|summary| !summary.is_empty())
+                .unwrap_or_else(|| "Untitled issue".to_string()),
+            status: name(fields.status).unwrap_or_else(|| "Unknown".to_string()),
+            updated: fields.updated.as_deref().map(rfc3339_offset),
+            assignee: fields.assignee.and_then(|person| person.display_name),
+            issue_type: name(fields.issuetype),
+            priority: name(fields.priority),
+        }
+    }
+}
+
+/// Jira writes offsets without a colon ("+0900"); RFC 3339 needs "+09:00".
+fn rfc3339_offset(timestamp: &str) -> String {
+    let split = timestamp.len().saturating_sub(5);
+    match timestamp.get(split..) {
+        Some(offset)
+            if offset.starts_with(['+', '-'])
+                && offset[1..].bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            format!("{}:{}", &timestamp[..split + 3], &offset[3..])
+        }
+        _ => timestamp.to_string(),
+    }
+}
+
+fn api_error(status: StatusCode, body: &str) -> String {
+    let parsed: Option<ApiError> = serde_json::from_str(body).ok();
+    parsed
+        .and_then(|error| {
+            error
+                .error_messages
+                .into_iter()
+                .next()
+                .or(error.message)
+                .or_else(|| error.errors.into_values().next())
+        })
+        .unwrap_or_else(|| format!("Jira request failed: {}", status.as_u16()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::test_support::serve;
+
+    /// `serve` with a single response and no extra headers.
+    fn serve_once(status: &str, body: &str) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        serve(&[(status, "", body)])
+    }
+
+    #[test]
+    fn fetches_profile_with_basic_auth() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            r#"{"accountId":"abc","displayName":"Kim","emailAddress":"kim@example.com"}"#,
+        );
+        let client = JiraClient::new(&base_url, "kim@example.com", "secret").unwrap();
+
+        let profile = tauri::async_runtime::block_on(client.myself()).unwrap();
+        assert_eq!(profile.display_name, "Kim");
+        assert_eq!(profile.account_id.as_deref(), Some("abc"));
+
+        let request = server.join().unwrap().remove(0);
+        assert!(request.starts_with("GET /rest/api/3/myself "));
+        // base64("kim@example.com:secret")
+        assert!(request.contains("Basic a2ltQGV4YW1wbGUuY29tOnNlY3JldA=="));
+    }
+
+    #[test]
+    fn searches_issues_with_jql() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            r#"{"issues":[{"key":"ABC-1","fields":{"summary":" Fix login ","status":{"name":"In Progress"},"assignee":{"displayName":"Kim"},"updated":"2026-01-22T10:00:00.000+0900"}},{"key":"ABC-2"}]}"#,
+        );
+        let client = JiraClient::new(&base_url, "kim@example.com", "secret").unwrap();
+
+        let issues = tauri::async_runtime::block_on(client.search("project = ABC", 20)).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].summary, "Fix login");
+        assert_eq!(issues[0].status, "In Progress");
+        assert_eq!(issues[0].url, format!("{}/browse/ABC-1", base_url));
+        assert_eq!(
+            issues[0].updated.as_deref(),
+            Some("2026-01-22T10:00:00.000+09:00")
+        );
+        assert_eq!(issues[1].summary, "Untitled issue");
+
+        let request = server.join().unwrap().remove(0);
+        assert!(request.starts_with("POST /rest/api/3/search/jql "));
+        assert!(request.contains(r#""jql":"project = ABC""#));
+    }
+
+    #[test]
+    fn surfaces_jira_error_messages() {
+        let (base_url, server) = serve_once(
+            "400 Bad Request",
+            r#"{"errorMessages":["Field 'foo' does not exist."],"errors":{}}"#,
+        );
+        let client = JiraClient::new(&base_url, "kim@example.com", "secret").unwrap();
+
+        let error = tauri::async_runtime::block_on(client.search("foo = 1", 20)).unwrap_err();
+        assert_eq!(error, "Field 'foo' does not exist.");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_rejected_credentials() {
+        let (base_url, server) = serve_once("401 Unauthorized", "Client must be authenticated");
+        let client = JiraClient::new(&base_url, "kim@example.com", "expired").unwrap();
+
+        let error = tauri::async_runtime::block_on(client.myself()).unwrap_err();
+        assert_eq!(error, UNAUTHORIZED_ERROR);
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,254 @@
+use regex::Regex;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+mod client;
+mod source;
+
+use super::secure::{
+    has_secret, record_secret_account, record_secret_check, secret_metadata, SecretAccount,
+    JIRA_TOKEN_KEY,
+};
+use client::{JiraClient, UNAUTHORIZED_ERROR};
+
+pub use source::JiraSourceSettings;
+
+/// Settings file shared with the frontend store.
+const STORE_NAME: &str = "settings.json";
+const STORE_KEY: &str = "jiraIntegration";
+const DEFAULT_MAX_RESULTS: u32 = 20;
+const MAX_RESULTS_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraUserProfile {
+    pub display_name: String,
+    pub email_address: Option<String>,
+    pub account_id: Option<String>,
+    pub avatar_urls: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraIssue {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    /// RFC 3339 timestamp of the last update.
+    pub updated: Option<String>,
+    pub assignee: Option<String>,
+    pub issue_type: Option<String>,
+    pub priority: Option<String>,
+    /// Browser URL of the issue.
+    pub url: String,
+}
+
+/// State of the saved API token. Timestamps are Unix milliseconds.
+#[derive(Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraCredentialStatus {
+    pub has_token: bool,
+    /// Whether Jira accepted the token on the last check or request. `None`
+    /// until checked, or when the site changed since.
+    pub valid: Option<bool>,
+    pub account_id: Option<String>,
+    pub email: Option<String>,
+    /// Site the token was verified against.
+    pub site: Option<String>,
+    /// The configured site differs from `site`.
+    pub site_changed: bool,
+    pub updated_at: Option<u64>,
+    pub last_used_at: Option<u64>,
+    pub last_success_at: Option<u64>,
+}
+
+/// Jira settings saved by the frontend. The token is kept in the keyring.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JiraStoredState {
+    #[serde(default)]
+    base_url: String,
+    #[serde(default)]
+    email: String,
+}
+
+/// "https://<site>.atlassian.net" for a Jira Cloud site URL.
+fn normalize_site_url(base_url: &str) -> Result<String, String> {
+    let url = Url::parse(base_url.trim()).map_err(|_| "Invalid Jira base URL".to_string())?;
+    if url.scheme() != "https" {
+        return Err("Jira base URL must start with https://".to_string());
+    }
+
+    let host = url.host_str().unwrap_or_default().to_lowercase();
+    let is_cloud_site = host.ends_with(".atlassian.net")
+        && matches!(url.path(), "" | "/")
+        && url.username().is_empty()
+        && url.password().is_none()
+        && url.port().is_none()
+        && url.query().is_none()
+        && url.fragment().is_none();
+    if !is_cloud_site {
+        return Err(
+            "Jira base URL must be a Jira Cloud site (e.g. https://your-domain.atlassian.net)"
+                .to_string(),
+        );
+    }
+    Ok(format!("https://{}", host))
+}
+
+fn validate_issue_key(key: &str) -> Result<(), String> {
+    let re = Regex::new(r"^[A-Z][A-Z0-9_]+-[1-9][0-9]*$").map_err(|e| e.to_string())?;
+    if !re.is_match(key) {
+        return Err("Issue key must look like ABC-123".to_string());
+    }
+    Ok(())
+}
+
+/// Site URL and email from the saved settings.
+fn jira_settings(app: &AppHandle) -> Result<(String, String), String> {
+    let store = app
+        .store(STORE_NAME)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    let stored: JiraStoredState = store
+        .get(STORE_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .ok_or("Jira is not configured")?;
+
+    let base_url = normalize_site_url(&stored.base_url)?;
+    let email = stored.email.trim();
+    if email.is_empty() {
+        return Err("Jira email is required".to_string());
+    }
+    Ok((base_url, email.to_string()))
+}
+
+/// Client for the configured site, with the saved token.
+fn jira_client(app: &AppHandle) -> Result<JiraClient, String> {
+    let (base_url, email) = jira_settings(app)?;
+    JiraClient::with_saved_token(&base_url, &email)
+}
+
+/// Note in the token's metadata whether Jira accepted it.
+fn track_credential<T>(result: Result<T, String>) -> Result<T, String> {
+    match &result {
+        Ok(_) => record_secret_check(JIRA_TOKEN_KEY, true),
+        Err(error) if error == UNAUTHORIZED_ERROR => record_secret_check(JIRA_TOKEN_KEY, false),
+        Err(_) => {}
+    }
+    result
+}
+
+fn account_of(profile: &JiraUserProfile, email: &str, site: &str) -> SecretAccount {
+    SecretAccount {
+        id: profile.account_id.clone(),
+        email: Some(
+            profile
+                .email_address
+                .clone()
+                .unwrap_or_else(|| email.to_string()),
+        ),
+        site: Some(site.to_string()),
+    }
+}
+
+/// Check `token` against the configured site before it is saved.
+pub(crate) async fn verify_jira_token(
+    app: &AppHandle,
+    token: &str,
+) -> Result<SecretAccount, String> {
+    let (base_url, email) = jira_settings(app)?;
+    let profile = JiraClient::new(&base_url, &email, token)?.myself().await?;
+    Ok(account_of(&profile, &email, &base_url))
+}
+
+/// Check the saved site, email and token by fetching the user's profile.
+#[tauri::command]
+#[specta::specta]
+pub async fn jira_test_connection(app: AppHandle) -> Result<JiraUserProfile, String> {
+    let profile = track_credential(jira_client(&app)?.myself().await)?;
+    if let Ok((base_url, email)) = jira_settings(&app) {
+        record_secret_account(JIRA_TOKEN_KEY, account_of(&profile, &email, &base_url));
+    }
+    Ok(profile)
+}
+
+/// Issues matching `jql`, at most `max_results` (default 20, up to 100).
+#[tauri::command]
+#[specta::specta]
+pub async fn jira_search_issues(
+    app: AppHandle,
+    jql: String,
+    max_results: Option<u32>,
+) -> Result<Vec<JiraIssue>, String> {
+    let jql = jql.trim();
+    if jql.is_empty() {
+        return Err("JQL is required".to_string());
+    }
+    let max_results = max_results
+        .unwrap_or(DEFAULT_MAX_RESULTS)
+        .clamp(1, MAX_RESULTS_LIMIT);
+
+    track_credential(jira_client(&app)?.search(jql, max_results).await)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn jira_get_issue(app: AppHandle, key: String) -> Result<JiraIssue, String> {
+    let key = key.trim().to_uppercase();
+    validate_issue_key(&key)?;
+
+    track_credential(jira_client(&app)?.issue(&key).await)
+}
+
+/// Whether a token is saved, the account it was verified against and when
+/// Jira last accepted it.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_jira_credential_status(app: AppHandle) -> Result<JiraCredentialStatus, String> {
+    let has_token = has_secret(JIRA_TOKEN_KEY)?;
+    let metadata = secret_metadata(JIRA_TOKEN_KEY)?;
+    let configured_site = jira_settings(&app).ok().map(|(base_url, _)| base_url);
+
+    let account = metadata.as_ref().and_then(|info| info.account.clone());
+    let site = account.as_ref().and_then(|account| account.site.clone());
+    let site_changed = matches!(
+        (&site, &configured_site),
+        (Some(site), Some(configured)) if site != configured
+    );
+
+    Ok(JiraCredentialStatus {
+        has_token,
+        valid: metadata
+            .as_ref()
+            .and_then(|info| info.valid)
+            .filter(|_| has_token && !site_changed),
+        account_id: account.as_ref().and_then(|account| account.id.clone()),
+        email: account.and_then(|account| account.email),
+        site,
+        site_changed,
+        updated_at: metadata.as_ref().and_then(|info| info.updated_at),
+        last_used_at: metadata.as_ref().and_then(|info| info.last_used_at),
+        last_success_at: metadata.as_ref().and_then(|info| info.last_success_at),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_only_cloud_site_urls() {
+        assert_eq!(
+            normalize_site_url("https://Example.atlassian.net/").as_deref(),
+            Ok("https://example.atlassian.net")
+        );
+        assert!(normalize_site_url("http://example.atlassian.net").is_err());
+        assert!(normalize_site_url("https://example.atlassian.net:8443").is_err());
+        assert!(normalize_site_url("https://example.atlassian.net/jira").is_err());
+        assert!(normalize_site_url("https://atlassian.net.example.com").is_err());
+    }
+}
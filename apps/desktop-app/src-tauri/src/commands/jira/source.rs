@@ -0,0 +1,80 @@
+use super::{jira_client, track_credential};
+use crate::commands::activity::{
+    ActivityItem, ActivitySource, ActivitySourceKind, ActivitySourceSettings,
+};
+use crate::commands::github::{add_days, kst_date_of};
+use futures_util::future::{BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::AppHandle;
+
+/// Issues in the queried window are capped at this many.
+const MAX_DAILY_ISSUES: u32 = 100;
+
+/// Uses the site and token saved in settings.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct JiraSourceSettings {}
+
+impl ActivitySourceSettings for JiraSourceSettings {
+    fn into_source(self, app: &AppHandle) -> Result<Box<dyn ActivitySource>, String> {
+        Ok(Box::new(JiraActivitySource::new(app.clone())))
+    }
+}
+
+/// Issues assigned to the user that were updated on the KST day.
+struct JiraActivitySource {
+    app: AppHandle,
+}
+
+impl JiraActivitySource {
+    pub fn new(app: AppHandle) -> Self {
+        Self { app }
+    }
+}
+
+impl ActivitySource for JiraActivitySource {
+    fn kind(&self) -> ActivitySourceKind {
+        ActivitySourceKind::Jira
+    }
+
+    fn daily_activity<'a>(
+        &'a self,
+        date: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<ActivityItem>, String>> {
+        async move {
+            // JQL dates are in the Jira user's time zone, which may not be KST.
+            // Query a day either side, then keep the issues updated on the KST day.
+            let invalid_date = || format!("Invalid date: {}", date);
+            let from = add_days(date, -1).ok_or_else(invalid_date)?;
+            let until = add_days(date, 2).ok_or_else(invalid_date)?;
+            let jql = format!(
+                "assignee = currentUser() AND updated >= \"{} 00:00\" AND updated < \"{} 00:00\" ORDER BY updated DESC",
+                from, until
+            );
+            let issues =
+                track_credential(jira_client(&self.app)?.search(&jql, MAX_DAILY_ISSUES).await)?;
+
+            Ok(issues
+                .into_iter()
+                .filter_map(|issue| {
+                    let timestamp = issue.updated?;
+                    if kst_date_of(&timestamp).as_deref() != Some(date) {
+                        return None;
+                    }
+                    let project = issue.key.split('-').next().map(str::to_string);
+                    Some(ActivityItem {
+                        source: ActivitySourceKind::Jira,
+                        kind: "issue".to_string(),
+                        title: issue.summary,
+                        url: Some(issue.url),
+                        timestamp,
+                        project,
+                        summary: Some(format!("{} · {}", issue.key, issue.status)),
+                        sha: None,
+                    })
+                })
+                .collect())
+        }
+        .boxed()
+    }
+}
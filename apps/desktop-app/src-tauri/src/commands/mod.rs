@@ -1,13 +1,21 @@
+pub mod activity;
 pub mod claude;
 pub mod file;
 pub mod github;
+pub mod jira;
 pub mod secure;
+pub mod settings;
 pub mod vault;
 pub mod whisper;
 
+#[cfg(test)]
+mod test_support;
+
+pub use activity::*;
 pub use claude::*;
 pub use file::*;
 pub use github::*;
+pub use jira::*;
 pub use secure::*;
 pub use vault::*;
 pub use whisper::*;
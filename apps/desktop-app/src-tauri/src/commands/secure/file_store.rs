@@ -0,0 +1,155 @@
+use super::store::SecretStore;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::RngCore;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const STORE_FILE: &str = "secrets.enc";
+const KEY_FILE: &str = "secrets.key";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// Secrets in a single file sealed with ChaCha20-Poly1305, used when no OS
+/// keyring is available (e.g. headless Linux without a Secret Service).
+///
+/// The key is kept next to the store in an owner-only file, so this is
+/// obfuscation rather than encryption at rest: anyone who can read the
+/// directory, or a backup that copies it whole, can recover the secrets.
+pub(super) struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    /// Cipher with the store key, creating the key on first use.
+    fn cipher(&self) -> Result<ChaCha20Poly1305, String> {
+        let path = self.dir.join(KEY_FILE);
+        let key = match fs::read(&path) {
+            Ok(key) if key.len() == KEY_LEN => key,
+            Ok(_) => return Err("Secret store key is corrupted".to_string()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let mut key = vec![0; KEY_LEN];
+                rand::rng().fill_bytes(&mut key);
+                write_private(&path, &key)?;
+                key
+            }
+            Err(e) => return Err(format!("Failed to read secret store key: {}", e)),
+        };
+        Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+    }
+
+    fn load(&self) -> Result<BTreeMap<String, String>, String> {
+        let data = match fs::read(self.dir.join(STORE_FILE)) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(e) => return Err(format!("Failed to read secret store: {}", e)),
+        };
+        if data.len() < NONCE_LEN {
+            return Err("Secret store is corrupted".to_string());
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher()?
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "Failed to decrypt secret store".to_string())?;
+        serde_json::from_slice(&plaintext).map_err(|e| format!("Secret store is corrupted: {}", e))
+    }
+
+    fn save(&self, entries: &BTreeMap<String, String>) -> Result<(), String> {
+        let plaintext = serde_json::to_vec(entries).map_err(|e| e.to_string())?;
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::rng().fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher()?
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| "Failed to encrypt secret store".to_string())?;
+
+        let mut data = nonce.to_vec();
+        data.extend_from_slice(&ciphertext);
+        write_private(&self.dir.join(STORE_FILE), &data)
+    }
+}
+
+impl SecretStore for FileStore {
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        Ok(self.load()?.remove(key))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        let mut entries = self.load()?;
+        entries.insert(key.to_string(), value.to_string());
+        self.save(&entries)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), String> {
+        let mut entries = self.load()?;
+        if entries.remove(key).is_some() {
+            self.save(&entries)?;
+        }
+        Ok(())
+    }
+}
+
+/// Replace `path` with `data`, readable only by the current user.
+pub(super) fn write_private(path: &Path, data: &[u8]) -> Result<(), String> {
+    let dir = path.parent().ok_or("Invalid secret store path")?;
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create secret store: {}", e))?;
+
+    let tmp_path = path.with_extension("tmp");
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options
+        .open(&tmp_path)
+        .map_err(|e| format!("Failed to write secret store: {}", e))?;
+    file.write_all(data)
+        .and_then(|()| file.sync_all())
+        .map_err(|e| format!("Failed to write secret store: {}", e))?;
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to write secret store: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::test_support::temp_dir;
+
+    #[test]
+    fn round_trips_encrypted_secrets() {
+        let dir = temp_dir("secrets");
+        let store = FileStore::new(&dir);
+
+        assert_eq!(store.get("jira-api-token"), Ok(None));
+        store.set("jira-api-token", "secret-token").unwrap();
+        store.set("github-token", "ghp_example").unwrap();
+        assert_eq!(
+            store.get("jira-api-token").unwrap().as_deref(),
+            Some("secret-token")
+        );
+
+        let raw = fs::read(dir.join(STORE_FILE)).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains("secret-token"));
+
+        store.delete("jira-api-token").unwrap();
+        assert_eq!(store.get("jira-api-token"), Ok(None));
+        assert_eq!(
+            store.get("github-token").unwrap().as_deref(),
+            Some("ghp_example")
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+use super::file_store::write_private;
+use super::SecretAccount;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const METADATA_FILE: &str = "secrets-metadata.json";
+/// Uses within this long of the last recorded one are not written again.
+const LAST_USED_RESOLUTION_MS: u64 = 60_000;
+
+/// Serializes read-modify-write of the metadata file.
+static METADATA_LOCK: Mutex<()> = Mutex::new(());
+
+/// What is known about one secret. Timestamps are Unix milliseconds. Never
+/// holds the value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(super) struct SecretMetadata {
+    pub created_at: Option<u64>,
+    pub updated_at: Option<u64>,
+    pub last_used_at: Option<u64>,
+    /// Last time the service accepted the secret.
+    #[serde(default)]
+    pub last_success_at: Option<u64>,
+    /// Whether the service last accepted the secret. `None` until checked.
+    #[serde(default)]
+    pub valid: Option<bool>,
+    #[serde(default)]
+    pub account: Option<SecretAccount>,
+}
+
+pub(super) type MetadataMap = BTreeMap<String, SecretMetadata>;
+
+pub(super) fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+pub(super) fn load(dir: &Path) -> MetadataMap {
+    fs::read_to_string(dir.join(METADATA_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(dir: &Path, metadata: &MetadataMap) -> Result<(), String> {
+    let content = serde_json::to_vec_pretty(metadata).map_err(|e| e.to_string())?;
+    write_private(&dir.join(METADATA_FILE), &content)
+}
+
+/// Apply `f` to the saved metadata under `METADATA_LOCK`, saving it when `f`
+/// reports a change.
+fn update(dir: &Path, f: impl FnOnce(&mut MetadataMap) -> bool) -> Result<(), String> {
+    let _guard = METADATA_LOCK
+        .lock()
+        .map_err(|e| format!("Failed to lock secret metadata: {}", e))?;
+    let mut metadata = load(dir);
+    if f(&mut metadata) {
+        save(dir, &metadata)?;
+    }
+    Ok(())
+}
+
+pub(super) fn record_set(dir: &Path, key: &str) -> Result<(), String> {
+    let now = now_millis();
+    update(dir, |metadata| {
+        let entry = metadata.entry(key.to_string()).or_default();
+        entry.created_at.get_or_insert(now);
+        entry.updated_at = Some(now);
+        // A new value has not been checked yet
+        entry.last_success_at = None;
+        entry.valid = None;
+        entry.account = None;
+        true
+    })
+}
+
+/// Record the account a just-verified secret belongs to.
+pub(super) fn record_account(dir: &Path, key: &str, account: SecretAccount) -> Result<(), String> {
+    update(dir, |metadata| {
+        let entry = metadata.entry(key.to_string()).or_default();
+        entry.account = Some(account);
+        entry.valid = Some(true);
+        entry.last_success_at = Some(now_millis());
+        true
+    })
+}
+
+/// Record whether the service accepted `key`.
+pub(super) fn record_check(dir: &Path, key: &str, valid: bool) -> Result<(), String> {
+    let now = now_millis();
+    update(dir, |metadata| {
+        let entry = metadata.entry(key.to_string()).or_default();
+        let unchanged = entry.valid == Some(valid)
+            && (!valid
+                || entry
+                    .last_success_at
+                    .is_some_and(|success| now.saturating_sub(success) < LAST_USED_RESOLUTION_MS));
+        if unchanged {
+            return false;
+        }
+        entry.valid = Some(valid);
+        if valid {
+            entry.last_success_at = Some(now);
+        }
+        true
+    })
+}
+
+/// Record a read of `key`. Secrets stored before metadata was kept are
+/// picked up here with unknown creation time.
+pub(super) fn record_use(dir: &Path, key: &str) -> Result<(), String> {
+    let now = now_millis();
+    update(dir, |metadata| {
+        let entry = metadata.entry(key.to_string()).or_default();
+        if entry
+            .last_used_at
+            .is_some_and(|used| now.saturating_sub(used) < LAST_USED_RESOLUTION_MS)
+        {
+            return false;
+        }
+        entry.last_used_at = Some(now);
+        true
+    })
+}
+
+pub(super) fn record_delete(dir: &Path, key: &str) -> Result<(), String> {
+    update(dir, |metadata| metadata.remove(key).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::test_support::temp_dir;
+    use std::thread;
+
+    #[test]
+    fn keeps_concurrent_updates() {
+        let dir = temp_dir("secret-metadata");
+        let threads: Vec<_> = (0..8)
+            .map(|i| {
+                let dir = dir.clone();
+                thread::spawn(move || {
+                    let key = format!("key-{}", i);
+                    record_set(&dir, &key).unwrap();
+                    record_check(&dir, &key, true).unwrap();
+                    record_use(&dir, &key).unwrap();
+                })
+            })
+            .collect();
+        for handle in threads {
+            handle.join().unwrap();
+        }
+        let metadata = load(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(metadata.len(), 8);
+        assert!(metadata
+            .values()
+            .all(|entry| entry.valid == Some(true) && entry.last_used_at.is_some()));
+    }
+}
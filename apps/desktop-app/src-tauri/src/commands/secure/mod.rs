@@ -0,0 +1,576 @@
+use super::github::{forget_github_login, github_hosts, normalize_host, DEFAULT_GITHUB_HOST};
+use super::jira::verify_jira_token;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+mod file_store;
+mod metadata;
+mod store;
+mod stronghold_store;
+
+use store::{keyring_available, open_store, SecretStore};
+
+pub(crate) const JIRA_TOKEN_KEY: &str = "jira-api-token";
+const GITHUB_TOKEN_KEY: &str = "github-token";
+const GOOGLE_REFRESH_TOKEN_KEY: &str = "google-oauth-refresh-token";
+/// Secrets the app may store. Keys in `HOST_SCOPED_KEYS` also exist per host
+/// as "<key>@<host>".
+const KNOWN_SECRET_KEYS: [&str; 3] = [JIRA_TOKEN_KEY, GITHUB_TOKEN_KEY, GOOGLE_REFRESH_TOKEN_KEY];
+const HOST_SCOPED_KEYS: [&str; 1] = [GITHUB_TOKEN_KEY];
+const MAX_SECRET_LEN: usize = 4096;
+/// Settings file shared with the frontend store.
+const SETTINGS_STORE: &str = "settings.json";
+const BACKEND_SETTING_KEY: &str = "secretBackend";
+
+static SECRET_PATHS: OnceLock<SecretPaths> = OnceLock::new();
+/// Backend chosen in settings. `None` means the default for the system.
+static CONFIGURED_BACKEND: Mutex<Option<SecretBackend>> = Mutex::new(None);
+/// Serializes access to the secret stores and metadata.
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretBackend {
+    /// The OS keyring (Keychain, Credential Manager, Secret Service).
+    Keyring,
+    /// File in the app data directory, sealed with a key file kept beside
+    /// it. This hides secrets from casual reads but does not protect them
+    /// from anyone who can read the directory. Saved as "encrypted_file"
+    /// before it was renamed.
+    #[serde(alias = "encrypted_file")]
+    LocalFile,
+    /// Stronghold snapshot in the app data directory. Its password is kept
+    /// beside it, so it is no safer than the file store against local reads.
+    Stronghold,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct SecretInfo {
+    pub key: String,
+    /// Unix milliseconds. Unknown for secrets saved before metadata was kept.
+    pub created_at: Option<u64>,
+    pub updated_at: Option<u64>,
+    /// Last time the app read the secret to call an integration.
+    pub last_used_at: Option<u64>,
+    /// Last time the service accepted the secret.
+    pub last_success_at: Option<u64>,
+    /// Whether the service last accepted the secret. `None` until checked.
+    pub valid: Option<bool>,
+    pub account: Option<SecretAccount>,
+}
+
+/// Account a secret was verified against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct SecretAccount {
+    pub id: Option<String>,
+    pub email: Option<String>,
+    /// Site or host the account lives on, e.g. "https://example.atlassian.net".
+    pub site: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type)]
+pub struct SecretMigrationResult {
+    /// Backend now in use.
+    pub backend: SecretBackend,
+    /// Keys copied to the new backend, verified and removed from the old one.
+    pub migrated: Vec<String>,
+}
+
+struct SecretPaths {
+    /// File-backed stores and the secret metadata.
+    dir: PathBuf,
+    stronghold_salt: PathBuf,
+}
+
+/// Set up paths and the configured backend. Called once during app setup.
+pub fn init_secret_store(app: &AppHandle) -> Result<(), String> {
+    let local_data_dir = app
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let _ = SECRET_PATHS.set(SecretPaths {
+        dir: local_data_dir.join("secrets"),
+        stronghold_salt: local_data_dir.join("stronghold_salt.txt"),
+    });
+
+    let store = app
+        .store(SETTINGS_STORE)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    let configured = store
+        .get(BACKEND_SETTING_KEY)
+        .and_then(|value| serde_json::from_value(value).ok());
+    *lock_backend()? = configured;
+    Ok(())
+}
+
+fn secret_paths() -> Result<&'static SecretPaths, String> {
+    SECRET_PATHS
+        .get()
+        .ok_or_else(|| "Secret store is not initialized".to_string())
+}
+
+fn lock_backend() -> Result<MutexGuard<'static, Option<SecretBackend>>, String> {
+    CONFIGURED_BACKEND
+        .lock()
+        .map_err(|e| format!("Failed to lock secret backend: {}", e))
+}
+
+fn lock_store() -> Result<MutexGuard<'static, ()>, String> {
+    STORE_LOCK
+        .lock()
+        .map_err(|e| format!("Failed to lock secret store: {}", e))
+}
+
+/// The configured backend, otherwise the keyring when one is reachable and
+/// the local file store when not.
+fn backend() -> SecretBackend {
+    let configured = lock_backend().ok().and_then(|backend| *backend);
+    configured.unwrap_or_else(|| {
+        if keyring_available() {
+            SecretBackend::Keyring
+        } else {
+            SecretBackend::LocalFile
+        }
+    })
+}
+
+fn active_store() -> Result<Box<dyn SecretStore>, String> {
+    open_store(backend(), secret_paths()?)
+}
+
+/// Best effort: metadata is informational and never blocks secret access.
+fn update_metadata(update: impl FnOnce(&Path) -> Result<(), String>) {
+    if let Ok(paths) = secret_paths() {
+        if let Err(error) = update(&paths.dir) {
+            eprintln!("Failed to update secret metadata: {}", error);
+        }
+    }
+}
+
+fn validate_secret_key(key: &str) -> Result<(), String> {
+    if KNOWN_SECRET_KEYS.contains(&key) {
+        return Ok(());
+    }
+    if let Some((base, host)) = key.split_once('@') {
+        let is_host = normalize_host(host).is_ok_and(|normalized| normalized == host);
+        if HOST_SCOPED_KEYS.contains(&base) && is_host && host != DEFAULT_GITHUB_HOST {
+            return Ok(());
+        }
+    }
+    Err(format!("Unknown secret key: {}", key))
+}
+
+fn load_secret(key: &str) -> Result<Option<String>, String> {
+    validate_secret_key(key)?;
+
+    let _guard = lock_store()?;
+    active_store()?.get(key)
+}
+
+/// Read a secret for use by an integration, recording the use.
+pub(crate) fn read_secret(key: &str) -> Result<Option<String>, String> {
+    let value = load_secret(key)?;
+    if value.is_some() {
+        if let Ok(_guard) = lock_store() {
+            update_metadata(|dir| metadata::record_use(dir, key));
+        }
+    }
+    Ok(value)
+}
+
+fn store_secret(key: &str, value: &str) -> Result<(), String> {
+    validate_secret_key(key)?;
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("Secret value is required".to_string());
+    }
+    if value.len() > MAX_SECRET_LEN {
+        return Err("Secret value is too long".to_string());
+    }
+
+    let _guard = lock_store()?;
+    active_store()?.set(key, value)?;
+    update_metadata(|dir| metadata::record_set(dir, key));
+    Ok(())
+}
+
+fn remove_secret(key: &str) -> Result<(), String> {
+    validate_secret_key(key)?;
+
+    let _guard = lock_store()?;
+    active_store()?.delete(key)?;
+    update_metadata(|dir| metadata::record_delete(dir, key));
+    Ok(())
+}
+
+/// Drop cached state derived from a secret that just changed.
+fn secret_changed(key: &str) {
+    if key == GITHUB_TOKEN_KEY {
+        forget_github_login(None);
+    } else if let Some(host) = key
+        .strip_prefix(GITHUB_TOKEN_KEY)
+        .and_then(|rest| rest.strip_prefix('@'))
+    {
+        forget_github_login(Some(host));
+    }
+}
+
+/// Every key that may hold a secret: the fixed keys, GitHub tokens of the
+/// configured hosts and anything with recorded metadata.
+fn candidate_keys(dir: &Path) -> BTreeSet<String> {
+    let mut keys: BTreeSet<String> = KNOWN_SECRET_KEYS.iter().map(|k| k.to_string()).collect();
+    for host in github_hosts().unwrap_or_default() {
+        if let Ok(key) = github_token_key(Some(&host.host)) {
+            keys.insert(key);
+        }
+    }
+    keys.extend(metadata::load(dir).into_keys());
+    keys.retain(|key| validate_secret_key(key).is_ok());
+    keys
+}
+
+/// Copy every secret from `source` to `target` and read it back. Returns the
+/// copied keys; nothing is removed from `source`.
+fn copy_verified(
+    keys: &BTreeSet<String>,
+    source: &dyn SecretStore,
+    target: &dyn SecretStore,
+) -> Result<Vec<String>, String> {
+    let mut copied = Vec::new();
+    for key in keys {
+        let Some(value) = source.get(key)? else {
+            continue;
+        };
+        target.set(key, &value)?;
+        if target.get(key)?.as_deref() != Some(value.as_str()) {
+            return Err(format!(
+                "Secret {} did not verify in the new backend; nothing was removed",
+                key
+            ));
+        }
+        copied.push(key.clone());
+    }
+    Ok(copied)
+}
+
+fn secret_info(key: String, metadata: metadata::SecretMetadata) -> SecretInfo {
+    SecretInfo {
+        key,
+        created_at: metadata.created_at,
+        updated_at: metadata.updated_at,
+        last_used_at: metadata.last_used_at,
+        last_success_at: metadata.last_success_at,
+        valid: metadata.valid,
+        account: metadata.account,
+    }
+}
+
+/// Whether `key` holds a value, without recording a use.
+pub(crate) fn has_secret(key: &str) -> Result<bool, String> {
+    Ok(load_secret(key)?.is_some_and(|value| !value.trim().is_empty()))
+}
+
+/// Metadata of `key`, if any has been recorded.
+pub(crate) fn secret_metadata(key: &str) -> Result<Option<SecretInfo>, String> {
+    validate_secret_key(key)?;
+    let _guard = lock_store()?;
+    let mut entries = metadata::load(&secret_paths()?.dir);
+    Ok(entries
+        .remove(key)
+        .map(|metadata| secret_info(key.to_string(), metadata)))
+}
+
+/// Record whether the service accepted `key` on the last request.
+pub(crate) fn record_secret_check(key: &str, valid: bool) {
+    if let Ok(_guard) = lock_store() {
+        update_metadata(|dir| metadata::record_check(dir, key, valid));
+    }
+}
+
+/// Record the account `key` was just verified against.
+pub(crate) fn record_secret_account(key: &str, account: SecretAccount) {
+    if let Ok(_guard) = lock_store() {
+        update_metadata(|dir| metadata::record_account(dir, key, account));
+    }
+}
+
+fn save_backend_setting(app: &AppHandle, backend: SecretBackend) -> Result<(), String> {
+    let store = app
+        .store(SETTINGS_STORE)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    store.set(BACKEND_SETTING_KEY, serde_json::json!(backend));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    *lock_backend()? = Some(backend);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_secret(service_key: String, value: String) -> Result<(), String> {
+    store_secret(&service_key, &value)?;
+    secret_changed(&service_key);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_secret(service_key: String) -> Result<(), String> {
+    remove_secret(&service_key)?;
+    secret_changed(&service_key);
+    Ok(())
+}
+
+/// Stored secrets with their metadata. Values are never returned here.
+#[tauri::command]
+#[specta::specta]
+pub async fn list_secret_keys() -> Result<Vec<SecretInfo>, String> {
+    let entries = {
+        let _guard = lock_store()?;
+        metadata::load(&secret_paths()?.dir)
+    };
+
+    Ok(entries
+        .into_iter()
+        .filter(|(key, _)| validate_secret_key(key).is_ok())
+        .map(|(key, metadata)| secret_info(key, metadata))
+        .collect())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_secret_backend() -> Result<SecretBackend, String> {
+    Ok(backend())
+}
+
+/// Move all secrets from `from` to `to` and switch to `to`. Secrets are only
+/// removed from `from` once every one of them reads back from `to`.
+#[tauri::command]
+#[specta::specta]
+pub async fn migrate_secrets(
+    app: AppHandle,
+    from: SecretBackend,
+    to: SecretBackend,
+) -> Result<SecretMigrationResult, String> {
+    if from == to {
+        return Err("Choose a different backend to migrate to".to_string());
+    }
+
+    let _guard = lock_store()?;
+    // Migrating from a store that is not in use would switch away from the
+    // secrets that are, leaving them behind.
+    if from != backend() {
+        return Err("Secrets can only be migrated from the active backend".to_string());
+    }
+    let paths = secret_paths()?;
+    let source = open_store(from, paths)?;
+    let target = open_store(to, paths)?;
+
+    let migrated = copy_verified(
+        &candidate_keys(&paths.dir),
+        source.as_ref(),
+        target.as_ref(),
+    )?;
+    save_backend_setting(&app, to)?;
+    for key in &migrated {
+        if let Err(error) = source.delete(key) {
+            eprintln!("Failed to remove migrated secret {}: {}", key, error);
+        }
+    }
+
+    Ok(SecretMigrationResult {
+        backend: to,
+        migrated,
+    })
+}
+
+/// Read the Jira API token.
+pub(crate) fn read_jira_token() -> Result<Option<String>, String> {
+    read_secret(JIRA_TOKEN_KEY)
+}
+
+/// Save the Jira API token. With `verify`, the token is first checked
+/// against the configured site and the account it belongs to is kept with it.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_jira_token(
+    app: AppHandle,
+    token: String,
+    verify: Option<bool>,
+) -> Result<(), String> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err("API token is required".to_string());
+    }
+    if token.len() > MAX_SECRET_LEN {
+        return Err("API token is too long".to_string());
+    }
+
+    let account = if verify.unwrap_or(false) {
+        Some(verify_jira_token(&app, token).await?)
+    } else {
+        None
+    };
+    store_secret(JIRA_TOKEN_KEY, token)?;
+    if let Some(account) = account {
+        record_secret_account(JIRA_TOKEN_KEY, account);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_jira_token() -> Result<(), String> {
+    remove_secret(JIRA_TOKEN_KEY)
+}
+
+/// Secret key of a GitHub host's token. github.com keeps the unsuffixed name.
+fn github_token_key(host: Option<&str>) -> Result<String, String> {
+    let host = match host {
+        Some(host) => normalize_host(host)?,
+        None => DEFAULT_GITHUB_HOST.to_string(),
+    };
+    if host == DEFAULT_GITHUB_HOST {
+        Ok(GITHUB_TOKEN_KEY.to_string())
+    } else {
+        Ok(format!("{}@{}", GITHUB_TOKEN_KEY, host))
+    }
+}
+
+/// Read the GitHub token for `host` (github.com when `None`).
+pub(crate) fn read_github_token(host: Option<&str>) -> Result<Option<String>, String> {
+    read_secret(&github_token_key(host)?)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_github_token(host: Option<String>) -> Result<Option<String>, String> {
+    load_secret(&github_token_key(host.as_deref())?)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_github_token(token: String, host: Option<String>) -> Result<(), String> {
+    if token.trim().is_empty() {
+        return Err("GitHub token is required".to_string());
+    }
+    if token.trim().len() > MAX_SECRET_LEN {
+        return Err("GitHub token is too long".to_string());
+    }
+    store_secret(&github_token_key(host.as_deref())?, &token)?;
+    forget_github_login(host.as_deref());
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_github_token(host: Option<String>) -> Result<(), String> {
+    let key = github_token_key(host.as_deref())?;
+    forget_github_login(host.as_deref());
+
+    remove_secret(&key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn allows_only_known_secret_keys() {
+        assert!(validate_secret_key("jira-api-token").is_ok());
+        assert!(validate_secret_key("github-token").is_ok());
+        assert!(validate_secret_key("github-token@github.example.com").is_ok());
+        assert!(validate_secret_key("google-oauth-refresh-token").is_ok());
+
+        assert!(validate_secret_key("github-token@github.com").is_err());
+        assert!(validate_secret_key("github-token@GitHub.Example.com").is_err());
+        assert!(validate_secret_key("jira-api-token@example.atlassian.net").is_err());
+        assert!(validate_secret_key("keyring-probe").is_err());
+    }
+
+    /// In-memory store that optionally drops writes.
+    struct MemoryStore {
+        entries: RefCell<BTreeMap<String, String>>,
+        drop_writes: bool,
+    }
+
+    impl MemoryStore {
+        fn new(entries: &[(&str, &str)], drop_writes: bool) -> Self {
+            Self {
+                entries: RefCell::new(
+                    entries
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                ),
+                drop_writes,
+            }
+        }
+    }
+
+    impl SecretStore for MemoryStore {
+        fn get(&self, key: &str) -> Result<Option<String>, String> {
+            Ok(self.entries.borrow().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: &str) -> Result<(), String> {
+            if !self.drop_writes {
+                self.entries
+                    .borrow_mut()
+                    .insert(key.to_string(), value.to_string());
+            }
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), String> {
+            self.entries.borrow_mut().remove(key);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn copies_and_verifies_present_secrets() {
+        let keys: BTreeSet<String> = ["github-token", "jira-api-token"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let source = MemoryStore::new(&[("jira-api-token", "secret")], false);
+        let target = MemoryStore::new(&[], false);
+
+        let copied = copy_verified(&keys, &source, &target).unwrap();
+        assert_eq!(copied, ["jira-api-token"]);
+        assert_eq!(target.get("jira-api-token"), Ok(Some("secret".to_string())));
+        assert_eq!(source.get("jira-api-token"), Ok(Some("secret".to_string())));
+
+        let lossy = MemoryStore::new(&[], true);
+        assert!(copy_verified(&keys, &source, &lossy).is_err());
+    }
+
+    #[test]
+    fn github_hosts_map_to_scoped_keys() {
+        assert_eq!(github_token_key(None).as_deref(), Ok("github-token"));
+        assert_eq!(
+            github_token_key(Some("https://GitHub.Example.com/")).as_deref(),
+            Ok("github-token@github.example.com")
+        );
+    }
+
+    #[test]
+    fn loads_backend_saved_under_old_name() {
+        let backend: SecretBackend =
+            serde_json::from_value(serde_json::json!("encrypted_file")).unwrap();
+        assert_eq!(backend, SecretBackend::LocalFile);
+        assert_eq!(serde_json::json!(backend), serde_json::json!("local_file"));
+    }
+}
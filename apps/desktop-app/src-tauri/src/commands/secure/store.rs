@@ -0,0 +1,75 @@
+use super::file_store::FileStore;
+use super::stronghold_store::StrongholdStore;
+use super::{SecretBackend, SecretPaths};
+use keyring::Entry;
+use std::sync::OnceLock;
+
+const KEYRING_SERVICE: &str = "com.raonc.en-ttokk";
+/// Keyring entry read once to find out whether a keyring is available.
+const PROBE_USERNAME: &str = "keyring-probe";
+
+static KEYRING_AVAILABLE: OnceLock<bool> = OnceLock::new();
+
+/// Storage for secret values. Callers hold the store lock.
+pub(super) trait SecretStore {
+    fn get(&self, key: &str) -> Result<Option<String>, String>;
+    fn set(&self, key: &str, value: &str) -> Result<(), String>;
+    fn delete(&self, key: &str) -> Result<(), String>;
+}
+
+/// The OS keyring (Keychain, Credential Manager, Secret Service).
+pub(super) struct KeyringStore;
+
+fn keyring_entry(key: &str) -> Result<Entry, String> {
+    Entry::new(KEYRING_SERVICE, key).map_err(|error| error.to_string())
+}
+
+impl SecretStore for KeyringStore {
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        match keyring_entry(key)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        keyring_entry(key)?
+            .set_password(value)
+            .map_err(|error| error.to_string())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), String> {
+        match keyring_entry(key)?.delete_password() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+}
+
+pub(super) fn keyring_available() -> bool {
+    *KEYRING_AVAILABLE.get_or_init(|| {
+        let probe = Entry::new(KEYRING_SERVICE, PROBE_USERNAME).and_then(|e| e.get_password());
+        !matches!(
+            probe,
+            Err(keyring::Error::PlatformFailure(_) | keyring::Error::NoStorageAccess(_))
+        )
+    })
+}
+
+pub(super) fn open_store(
+    backend: SecretBackend,
+    paths: &SecretPaths,
+) -> Result<Box<dyn SecretStore>, String> {
+    match backend {
+        SecretBackend::Keyring if !keyring_available() => {
+            Err("No OS keyring is available on this system".to_string())
+        }
+        SecretBackend::Keyring => Ok(Box::new(KeyringStore)),
+        SecretBackend::LocalFile => Ok(Box::new(FileStore::new(&paths.dir))),
+        SecretBackend::Stronghold => Ok(Box::new(StrongholdStore::new(
+            &paths.dir,
+            &paths.stronghold_salt,
+        ))),
+    }
+}
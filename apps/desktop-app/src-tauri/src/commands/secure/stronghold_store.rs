@@ -0,0 +1,183 @@
+use super::file_store::write_private;
+use super::store::SecretStore;
+use iota_stronghold::Client;
+use rand::RngCore;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use tauri_plugin_stronghold::kdf::KeyDerivation;
+use tauri_plugin_stronghold::stronghold::Stronghold;
+
+const SNAPSHOT_FILE: &str = "secrets.stronghold";
+const PASSWORD_FILE: &str = "stronghold.key";
+const PASSWORD_LEN: usize = 32;
+const CLIENT_PATH: &[u8] = b"en-ttokk-secrets";
+
+/// Opened snapshot. Unlocking runs argon2, so it is done once per run.
+static STRONGHOLD: Mutex<Option<Stronghold>> = Mutex::new(None);
+
+/// Secrets in a Stronghold snapshot, unlocked with a generated password kept
+/// in plain hex in an owner-only file next to it. Like the file store, this
+/// does not protect the secrets from anyone who can read the directory.
+pub(super) struct StrongholdStore {
+    dir: PathBuf,
+    salt_path: PathBuf,
+}
+
+impl StrongholdStore {
+    pub fn new(dir: &Path, salt_path: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            salt_path: salt_path.to_path_buf(),
+        }
+    }
+
+    /// Snapshot password, created on first use.
+    fn password(&self) -> Result<String, String> {
+        let path = self.dir.join(PASSWORD_FILE);
+        match fs::read_to_string(&path) {
+            Ok(password) if is_valid_password(&password) => Ok(password),
+            Ok(_) => Err("Stronghold password file is corrupted".to_string()),
+            // A new password could never unlock the snapshot already there
+            Err(e)
+                if e.kind() == std::io::ErrorKind::NotFound
+                    && self.dir.join(SNAPSHOT_FILE).exists() =>
+            {
+                Err("Stronghold password file is missing".to_string())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let mut bytes = [0u8; PASSWORD_LEN];
+                rand::rng().fill_bytes(&mut bytes);
+                let password: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                write_private(&path, password.as_bytes())?;
+                Ok(password)
+            }
+            Err(e) => Err(format!("Failed to read Stronghold password: {}", e)),
+        }
+    }
+
+    fn open(&self) -> Result<MutexGuard<'static, Option<Stronghold>>, String> {
+        let mut guard = STRONGHOLD
+            .lock()
+            .map_err(|e| format!("Failed to lock Stronghold: {}", e))?;
+        if guard.is_none() {
+            fs::create_dir_all(&self.dir)
+                .map_err(|e| format!("Failed to create secret store: {}", e))?;
+            let key = KeyDerivation::argon2(&self.password()?, &self.salt_path);
+            let stronghold = Stronghold::new(self.dir.join(SNAPSHOT_FILE), key)
+                .map_err(|e| format!("Failed to open Stronghold: {}", e))?;
+            *guard = Some(stronghold);
+        }
+        Ok(guard)
+    }
+
+    fn with_client<T>(
+        &self,
+        f: impl FnOnce(&Stronghold, &Client) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let guard = self.open()?;
+        let stronghold = guard.as_ref().ok_or("Stronghold is not open")?;
+        let client = stronghold
+            .get_client(CLIENT_PATH)
+            .or_else(|_| stronghold.load_client(CLIENT_PATH))
+            .or_else(|_| stronghold.create_client(CLIENT_PATH))
+            .map_err(|e| format!("Failed to open Stronghold client: {}", e))?;
+        f(stronghold, &client)
+    }
+}
+
+/// A generated password: `PASSWORD_LEN` bytes as lowercase hex.
+fn is_valid_password(password: &str) -> bool {
+    password.len() == PASSWORD_LEN * 2
+        && password
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+/// Persist the client's store to the snapshot file.
+fn commit(stronghold: &Stronghold) -> Result<(), String> {
+    stronghold
+        .write_client(CLIENT_PATH)
+        .map_err(|e| format!("Failed to write Stronghold client: {}", e))?;
+    stronghold
+        .save()
+        .map_err(|e| format!("Failed to save Stronghold: {}", e))
+}
+
+impl SecretStore for StrongholdStore {
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        self.with_client(|_, client| {
+            let value = client
+                .store()
+                .get(key.as_bytes())
+                .map_err(|e| format!("Failed to read from Stronghold: {}", e))?;
+            value
+                .map(|value| {
+                    String::from_utf8(value).map_err(|_| "Stored secret is not UTF-8".to_string())
+                })
+                .transpose()
+        })
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        self.with_client(|stronghold, client| {
+            client
+                .store()
+                .insert(key.as_bytes().to_vec(), value.as_bytes().to_vec(), None)
+                .map_err(|e| format!("Failed to write to Stronghold: {}", e))?;
+            commit(stronghold)
+        })
+    }
+
+    fn delete(&self, key: &str) -> Result<(), String> {
+        self.with_client(|stronghold, client| {
+            let removed = client
+                .store()
+                .delete(key.as_bytes())
+                .map_err(|e| format!("Failed to delete from Stronghold: {}", e))?;
+            if removed.is_some() {
+                commit(stronghold)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::test_support::temp_dir;
+
+    #[test]
+    fn rejects_corrupted_password_file() {
+        let dir = temp_dir("stronghold");
+        let store = StrongholdStore::new(&dir, &dir.join("salt.txt"));
+
+        let password = store.password().unwrap();
+        assert!(is_valid_password(&password));
+        assert_eq!(store.password().as_deref(), Ok(password.as_str()));
+
+        fs::write(dir.join(PASSWORD_FILE), &password[..PASSWORD_LEN]).unwrap();
+        let truncated = store.password();
+        fs::write(dir.join(PASSWORD_FILE), password.to_uppercase() + "\n").unwrap();
+        let altered = store.password();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(truncated.is_err());
+        assert!(altered.is_err());
+    }
+
+    #[test]
+    fn rejects_missing_password_file_for_existing_snapshot() {
+        let dir = temp_dir("stronghold-missing");
+        fs::write(dir.join(SNAPSHOT_FILE), b"snapshot").unwrap();
+        let store = StrongholdStore::new(&dir, &dir.join("salt.txt"));
+
+        let result = store.password();
+        let created = dir.join(PASSWORD_FILE).exists();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(result.unwrap_err().contains("missing"));
+        assert!(!created);
+    }
+}
@@ -0,0 +1,28 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Settings file shared with the frontend store.
+const STORE_NAME: &str = "settings.json";
+
+/// Value saved under `key`, if there is one and it parses.
+pub(crate) fn load_setting<T: DeserializeOwned>(app: &AppHandle, key: &str) -> Option<T> {
+    let store = app.store(STORE_NAME).ok()?;
+    serde_json::from_value(store.get(key)?).ok()
+}
+
+pub(crate) fn save_setting<T: Serialize>(
+    app: &AppHandle,
+    key: &str,
+    value: &T,
+) -> Result<(), String> {
+    let store = app
+        .store(STORE_NAME)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    let value = serde_json::to_value(value).map_err(|e| e.to_string())?;
+    store.set(key, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))
+}
@@ -0,0 +1,67 @@
+//! Fixtures shared by the command tests.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+
+/// An empty directory under the system temp dir. `name` keeps tests that run
+/// in parallel apart; the process id keeps concurrent test runs apart.
+pub(crate) fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("en-ttokk-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Serve `(status, extra headers, body)` responses on a local port, one
+/// connection each, in order. Extra headers end in "\r\n", and `{base}` in
+/// them is replaced with the server URL. The handle yields the request line,
+/// headers and body of each request as received.
+pub(crate) fn serve(responses: &[(&str, &str, &str)]) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let responses: Vec<String> = responses
+        .iter()
+        .map(|(status, headers, body)| {
+            format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                headers.replace("{base}", &base_url),
+                body
+            )
+        })
+        .collect();
+
+    let handle = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8_lossy(&body));
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            requests.push(request);
+        }
+        requests
+    });
+
+    (base_url, handle)
+}
@@ -2,10 +2,20 @@ mod commands;
 mod sidecar;
 
 use commands::{
-    create_file, create_folder, create_vault, delete_file, get_all_notes,
-    get_claude_activities, get_claude_activity_dates, get_github_activity, get_jira_token,
-    list_claude_projects, open_vault, read_directory, read_file, remove_jira_token, rename_file,
-    set_jira_token, validate_vault_path, write_file,
+    create_file, create_folder, create_vault, delete_file, delete_secret, get_all_notes,
+    get_daily_activity, get_github_activity, get_github_activity_range, get_github_hosts,
+    get_github_inbox, get_github_token, get_jira_credential_status, get_local_git_activity,
+    get_secret_backend, init_secret_store, jira_get_issue, jira_search_issues,
+    jira_test_connection, list_secret_keys, load_claude_data_dirs, load_github_hosts,
+    load_redaction_settings, migrate_secrets, open_vault, read_directory, read_file,
+    remove_github_token, remove_jira_token, rename_file, set_github_hosts, set_github_token,
+    set_jira_token, set_secret, validate_vault_path, write_file,
+    // Claude commands
+    export_claude_session, get_claude_activities, get_claude_activity_dates,
+    get_claude_activity_histogram, get_claude_data_dirs, get_claude_redaction_settings,
+    get_claude_session, get_claude_usage, is_claude_activity_watch_active, list_claude_projects,
+    preview_subscription, search_claude_history, set_claude_data_dirs,
+    set_claude_redaction_settings, start_claude_activity_watch, stop_claude_activity_watch,
     // Whisper commands
     cancel_transcription, cancel_whisper_download, check_whisper_model, cleanup_partial_download,
     cleanup_recording, download_whisper_model, list_recordings, save_recorded_audio,
@@ -44,16 +54,48 @@ fn create_specta_builder() -> Builder {
         rename_file,
         create_folder,
         get_all_notes,
+        // Activity commands
+        get_daily_activity,
+        // Secret commands
+        set_secret,
+        delete_secret,
+        list_secret_keys,
+        get_secret_backend,
+        migrate_secrets,
         // GitHub commands
         get_github_activity,
+        get_github_activity_range,
+        get_github_inbox,
+        get_local_git_activity,
+        get_github_hosts,
+        set_github_hosts,
+        get_github_token,
+        set_github_token,
+        remove_github_token,
         // Jira commands
-        get_jira_token,
         set_jira_token,
         remove_jira_token,
+        get_jira_credential_status,
+        jira_test_connection,
+        jira_search_issues,
+        jira_get_issue,
         // Claude commands
+        get_claude_data_dirs,
+        set_claude_data_dirs,
+        get_claude_redaction_settings,
+        set_claude_redaction_settings,
         list_claude_projects,
+        preview_subscription,
         get_claude_activities,
         get_claude_activity_dates,
+        get_claude_activity_histogram,
+        get_claude_session,
+        export_claude_session,
+        get_claude_usage,
+        search_claude_history,
+        start_claude_activity_watch,
+        stop_claude_activity_watch,
+        is_claude_activity_watch_active,
         // Whisper commands
         check_whisper_model,
         download_whisper_model,
@@ -95,13 +137,10 @@ pub fn run() {
         .manage(sidecar::SidecarState::default())
         .invoke_handler(builder.invoke_handler())
         .setup(move |app| {
-            let salt_path = app
-                .path()
-                .app_local_data_dir()?
-                .join("stronghold_salt.txt");
-            app.handle().plugin(
-                tauri_plugin_stronghold::Builder::with_argon2(&salt_path).build(),
-            )?;
+            init_secret_store(app.handle())?;
+            load_github_hosts(app.handle());
+            load_claude_data_dirs(app.handle());
+            load_redaction_settings(app.handle());
 
             // Spawn backend sidecar only in release mode
             // In dev mode, backend is started separately via `bun run dev:full`
@@ -3,9 +3,14 @@ export type ClaudeActivityKind = "user" | "assistant";
 export interface ClaudeActivityItem {
   kind: ClaudeActivityKind;
   content: string;
+  redaction_count: number;
   timestamp: string;
   project_path: string;
   session_id: string;
+  source_root: string;
+  uuid: string | null;
+  is_sidechain: boolean;
+  sidechain: ClaudeActivityItem[];
 }
 
 export interface ClaudeActivityResponse {
@@ -18,3 +23,10 @@ export type ClaudeActivityStatus = "idle" | "loading" | "loaded" | "error";
 export interface ClaudeSubscriptionSettings {
   subscribedFolders: string[];
 }
+
+export interface ClaudeRedactionSettings {
+  enabled: boolean;
+  /** Also redact long random-looking tokens. */
+  high_entropy: boolean;
+  custom_patterns: string[];
+}
@@ -1,29 +1,28 @@
 import { formatInKst, getKstDateKey } from "@bun-enttokk/shared";
-import type { JiraIssue } from "@enttokk/api-types";
 import { isValid, parse } from "date-fns";
 import { commands } from "@/bindings";
 import { useClaudeActivityStore } from "@/features/claude-activity/store/claudeActivityStore";
-import type { ClaudeActivityItem } from "@/features/claude-activity/types";
 import { DEFAULT_DAILY_NOTES_SETTINGS } from "@/features/daily-notes/types";
-import type { GitHubActivityItem } from "@/features/github/types";
 import { useGoogleCalendarStore } from "@/features/google-calendar/store/googleCalendarStore";
-import { useJiraStore } from "@/features/jira/store/jiraStore";
 import { useSettingsStore } from "@/features/settings/store/settingsStore";
 import { useVaultStore } from "@/features/vault/store/vaultStore";
-import { getClaudeActivities } from "@/lib/claude";
-import { getGitHubActivity } from "@/lib/github";
+import { getDailyActivity } from "@/lib/activity";
 import { htmlToMarkdown } from "@/lib/markdown";
 
 import {
   type DailyNoteResult,
-  formatClaudeActivityLines,
+  formatActivityLines,
   formatDailyNoteBlock,
-  formatGitHubLines,
   formatGoogleCalendarLines,
-  formatJiraLines,
   formatStatusLabel,
   type SourceStatus,
 } from "./formatters";
+import type {
+  ActivityItem,
+  ActivitySourceConfig,
+  ActivitySourceKind,
+  DailyActivityResponse,
+} from "./types";
 
 export type DailySummaryRequest = {
   displayMessage: string;
@@ -94,20 +93,64 @@ const loadDailyNoteContent = async (date: Date): Promise<DailyNoteResult> => {
   }
 };
 
-const resolveGitHubStatus = (message: string): SourceStatus => {
+const resolveSourceStatus = (message: string): SourceStatus => {
   const lowered = message.toLowerCase();
   if (
     lowered.includes("auth login") ||
     lowered.includes("not available") ||
     lowered.includes("gh) not found") ||
     lowered.includes("not logged into any hosts") ||
-    lowered.includes("not authenticated")
+    lowered.includes("not authenticated") ||
+    lowered.includes("not configured") ||
+    lowered.includes("token is not set")
   ) {
     return "disconnected";
   }
   return "error";
 };
 
+const loadClaudeFolders = async (): Promise<string[]> => {
+  try {
+    await useClaudeActivityStore.getState().loadSavedFolders();
+    return useClaudeActivityStore.getState().subscribedFolders;
+  } catch {
+    return [];
+  }
+};
+
+type SourceResult = {
+  status: SourceStatus;
+  error: string | null;
+  items: ActivityItem[];
+};
+
+/** Status and items of one source in the merged daily activity. */
+const sourceResult = (
+  response: DailyActivityResponse | null,
+  requestError: string | null,
+  source: ActivitySourceKind
+): SourceResult => {
+  const status = response?.sources.find((entry) => entry.source === source);
+  const error = status ? (status.error ?? null) : requestError;
+  if (!status || error) {
+    return {
+      status: error ? resolveSourceStatus(error) : "unknown",
+      error,
+      items: [],
+    };
+  }
+  return {
+    status: "connected",
+    error: null,
+    items: response?.items.filter((item) => item.source === source) ?? [],
+  };
+};
+
+const formatSourceStatus = (result: SourceResult) =>
+  `상태: ${formatStatusLabel(result.status)}${
+    result.error ? ` (${result.error})` : ""
+  }`;
+
 export async function buildDailySummaryPrompt(
   dateInput?: Date
 ): Promise<DailySummaryRequest> {
@@ -115,83 +158,32 @@ export async function buildDailySummaryPrompt(
   const targetKey = getKstDateKey(date);
   const dateLabel = formatInKst(date, "yyyy-MM-dd");
 
-  const [dailyNoteResult, githubResult, jiraResult, claudeResult] =
-    await Promise.all([
-      loadDailyNoteContent(date),
-      getGitHubActivity(targetKey)
-        .then((response) => ({
-          status: "connected" as const,
-          items: response.items ?? [],
-          error: null as string | null,
-        }))
-        .catch((error) => {
-          const message =
-            error instanceof Error ? error.message : String(error);
-          return {
-            status: resolveGitHubStatus(message),
-            items: [] as GitHubActivityItem[],
-            error: message,
-          };
-        }),
-      (async () => {
-        try {
-          const store = useJiraStore.getState();
-          await store.loadFromStore();
-          const state = useJiraStore.getState();
-          if (state.status === "connected") {
-            await state.fetchIssues();
-          }
-          const nextState = useJiraStore.getState();
-          const resolvedStatus: SourceStatus =
-            nextState.status === "connected"
-              ? "connected"
-              : nextState.status === "error"
-                ? "error"
-                : "disconnected";
-          return {
-            status: resolvedStatus,
-            error: nextState.error,
-            issues: nextState.issues,
-          };
-        } catch (error) {
-          return {
-            status: "error" as const,
-            error: error instanceof Error ? error.message : "Jira load failed",
-            issues: [] as JiraIssue[],
-          };
-        }
-      })(),
-      (async () => {
-        try {
-          const claudeStore = useClaudeActivityStore.getState();
-          await claudeStore.loadSavedFolders();
-          const { subscribedFolders } = useClaudeActivityStore.getState();
-          if (subscribedFolders.length === 0) {
-            return {
-              status: "disconnected" as SourceStatus,
-              items: [] as ClaudeActivityItem[],
-              error: "구독 폴더 없음",
-            };
-          }
-          const response = await getClaudeActivities(
-            targetKey,
-            subscribedFolders
-          );
-          return {
-            status: "connected" as SourceStatus,
-            items: response.items ?? [],
-            error: null as string | null,
-          };
-        } catch (error) {
-          return {
-            status: "error" as SourceStatus,
-            items: [] as ClaudeActivityItem[],
-            error:
-              error instanceof Error ? error.message : "Claude activity failed",
-          };
-        }
-      })(),
-    ]);
+  const subscribedFolders = await loadClaudeFolders();
+  const sources: ActivitySourceConfig[] = [
+    { source: "github" },
+    { source: "jira" },
+  ];
+  if (subscribedFolders.length > 0) {
+    sources.push({ source: "claude", subscribed_folders: subscribedFolders });
+  }
+
+  const [dailyNoteResult, activityResult] = await Promise.all([
+    loadDailyNoteContent(date),
+    getDailyActivity(targetKey, sources)
+      .then((response) => ({ response, error: null as string | null }))
+      .catch((error) => ({
+        response: null,
+        error: error instanceof Error ? error.message : String(error),
+      })),
+  ]);
+
+  const { response, error: requestError } = activityResult;
+  const githubResult = sourceResult(response, requestError, "github");
+  const jiraResult = sourceResult(response, requestError, "jira");
+  const claudeResult: SourceResult =
+    subscribedFolders.length > 0
+      ? sourceResult(response, requestError, "claude")
+      : { status: "disconnected", error: "구독 폴더 없음", items: [] };
 
   const googleState = useGoogleCalendarStore.getState();
   const googleStatus: SourceStatus =
@@ -203,9 +195,12 @@ export async function buildDailySummaryPrompt(
 
   const dailyNoteBlock = formatDailyNoteBlock(dailyNoteResult);
   const googleLines = formatGoogleCalendarLines(googleState.events, targetKey);
-  const jiraLines = formatJiraLines(jiraResult.issues ?? [], targetKey);
-  const githubLines = formatGitHubLines(githubResult.items ?? []);
-  const claudeLines = formatClaudeActivityLines(claudeResult.items ?? []);
+  const jiraLines = formatActivityLines(jiraResult.items);
+  const githubLines = formatActivityLines(githubResult.items);
+  // Prompts describe the work; replies would crowd them out
+  const claudeLines = formatActivityLines(
+    claudeResult.items.filter((item) => item.kind === "user")
+  );
 
   const requestMessage = [
     "당신은 업무일지를 작성하는 비서입니다.",
@@ -233,21 +228,15 @@ export async function buildDailySummaryPrompt(
     ...googleLines.lines,
     "",
     "[Jira]",
-    `상태: ${formatStatusLabel(jiraResult.status)}${
-      jiraResult.error ? ` (${jiraResult.error})` : ""
-    }`,
+    formatSourceStatus(jiraResult),
     ...jiraLines.lines,
     "",
     "[GitHub]",
-    `상태: ${formatStatusLabel(githubResult.status)}${
-      githubResult.error ? ` (${githubResult.error})` : ""
-    }`,
+    formatSourceStatus(githubResult),
     ...githubLines.lines,
     "",
     "[Claude Activity]",
-    `상태: ${formatStatusLabel(claudeResult.status)}${
-      claudeResult.error ? ` (${claudeResult.error})` : ""
-    }`,
+    formatSourceStatus(claudeResult),
     ...claudeLines.lines,
   ].join("\n");
 
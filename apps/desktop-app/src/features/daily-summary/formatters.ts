@@ -1,14 +1,13 @@
 import { formatInKst } from "@bun-enttokk/shared";
-import type { GoogleCalendarEvent, JiraIssue } from "@enttokk/api-types";
+import type { GoogleCalendarEvent } from "@enttokk/api-types";
 import { isValid, parseISO } from "date-fns";
 
-import type { ClaudeActivityItem } from "@/features/claude-activity/types";
-import type { GitHubActivityItem } from "@/features/github/types";
 import { getEventDateKey } from "@/features/google-calendar/utils/dates";
 
+import type { ActivityItem } from "./types";
+
 const MAX_DAILY_NOTE_CHARS = 4000;
 const MAX_LIST_ITEMS = 20;
-const MAX_CLAUDE_SESSIONS = 10;
 const CLAUDE_TASK_SUMMARY_LENGTH = 80;
 
 export type DailyNoteResult = {
@@ -73,45 +72,46 @@ export const formatGoogleCalendarLines = (
   return { lines, truncated };
 };
 
-const getIssueUpdatedKey = (updated?: string) => {
-  if (!updated) return null;
-  const parsed = parseISO(updated);
-  if (!isValid(parsed)) return null;
-  return formatInKst(parsed, "yyyy-MM-dd");
+const githubKindLabel: Record<string, string> = {
+  commit: "Commit",
+  pull_request: "PR",
+  review: "Review",
+  comment: "Comment",
+  merged: "Merged",
+  issue_opened: "Issue",
+  issue_closed: "Closed",
+  released: "Release",
 };
 
-export const formatJiraLines = (issues: JiraIssue[], targetKey: string) => {
-  const filtered = issues.filter(
-    (issue) => getIssueUpdatedKey(issue.updated) === targetKey
-  );
-  const { items, truncated } = limitItems(filtered, MAX_LIST_ITEMS);
-  const lines = items.map((issue) => {
-    const updatedLabel = issue.updated ? formatTimestamp(issue.updated) : "";
-    const updatedSuffix = updatedLabel ? ` (updated ${updatedLabel})` : "";
-    return `- ${issue.key} ${issue.summary} [${issue.status}]${updatedSuffix}`;
-  });
-  if (lines.length === 0) {
-    return { lines: ["- 없음"], truncated: 0 };
-  }
-  if (truncated > 0) {
-    lines.push(`- ... 외 ${truncated}건`);
+const projectName = (project?: string) => {
+  if (!project) return "Unknown";
+  const pathParts = project.split("/");
+  return pathParts[pathParts.length - 1] || project;
+};
+
+const formatActivityLine = (item: ActivityItem) => {
+  const timeLabel = formatTimestamp(item.timestamp);
+  switch (item.source) {
+    case "jira": {
+      const summary = item.summary ? `${item.summary} ` : "";
+      return `- ${summary}${item.title} (updated ${timeLabel})`;
+    }
+    case "claude": {
+      const title = truncateText(item.title, CLAUDE_TASK_SUMMARY_LENGTH);
+      return `- [${projectName(item.project)}] ${title} · ${timeLabel}`;
+    }
+    default: {
+      const label = githubKindLabel[item.kind] ?? item.kind;
+      const summary = item.summary ? ` (${item.summary})` : "";
+      const project = item.project ? ` · ${item.project}` : "";
+      return `- ${label} ${item.title}${summary}${project} · ${timeLabel}`;
+    }
   }
-  return { lines, truncated };
 };
 
-export const formatGitHubLines = (items: GitHubActivityItem[]) => {
+export const formatActivityLines = (items: ActivityItem[]) => {
   const { items: trimmed, truncated } = limitItems(items, MAX_LIST_ITEMS);
-  const kindLabel: Record<GitHubActivityItem["kind"], string> = {
-    commit: "Commit",
-    pull_request: "PR",
-    review: "Review",
-    comment: "Comment",
-  };
-  const lines = trimmed.map((item) => {
-    const timeLabel = formatTimestamp(item.timestamp);
-    const summary = item.summary ? ` (${item.summary})` : "";
-    return `- ${kindLabel[item.kind]} ${item.title}${summary} · ${item.repo} · ${timeLabel}`;
-  });
+  const lines = trimmed.map(formatActivityLine);
   if (lines.length === 0) {
     return { lines: ["- 없음"], truncated: 0 };
   }
@@ -121,51 +121,6 @@ export const formatGitHubLines = (items: GitHubActivityItem[]) => {
   return { lines, truncated };
 };
 
-export const formatClaudeActivityLines = (items: ClaudeActivityItem[]) => {
-  const sessionGroups = new Map<string, ClaudeActivityItem[]>();
-  for (const item of items) {
-    if (!sessionGroups.has(item.session_id)) {
-      sessionGroups.set(item.session_id, []);
-    }
-    sessionGroups.get(item.session_id)?.push(item);
-  }
-
-  const lines: string[] = [];
-  let sessionCount = 0;
-
-  for (const sessionItems of sessionGroups.values()) {
-    if (sessionCount >= MAX_CLAUDE_SESSIONS) {
-      const remaining = sessionGroups.size - MAX_CLAUDE_SESSIONS;
-      lines.push(`- ... 외 ${remaining}개 세션`);
-      break;
-    }
-
-    const projectPath = sessionItems[0]?.project_path ?? "Unknown";
-    const pathParts = projectPath.split("/");
-    const projectName = pathParts[pathParts.length - 1] ?? projectPath;
-
-    const userMessages = sessionItems.filter((item) => item.kind === "user");
-    const firstUserMessage = userMessages[0]?.content ?? "";
-    const firstLine = firstUserMessage.split("\n")[0] ?? "";
-    const taskSummary = truncateText(firstLine, CLAUDE_TASK_SUMMARY_LENGTH);
-
-    const timestamp = sessionItems[0]?.timestamp;
-    const timeLabel = timestamp ? formatTimestamp(timestamp) : "";
-    const label = taskSummary || "대화 세션";
-
-    lines.push(
-      `- [${projectName}] ${label} (${userMessages.length}개 질문) · ${timeLabel}`
-    );
-    sessionCount++;
-  }
-
-  if (lines.length === 0) {
-    return { lines: ["- 없음"], truncated: 0 };
-  }
-
-  return { lines, truncated: 0 };
-};
-
 export const formatDailyNoteBlock = (result: DailyNoteResult) => {
   if (result.status === "available" && result.content) {
     return truncateText(result.content.trim(), MAX_DAILY_NOTE_CHARS) || "없음";
@@ -0,0 +1,32 @@
+import type { GitHubRepoFilter } from "@/features/github/types";
+
+export type ActivitySourceKind = "github" | "local_git" | "claude" | "jira";
+
+export interface ActivityItem {
+  source: ActivitySourceKind;
+  kind: string;
+  title: string;
+  url?: string;
+  timestamp: string;
+  project?: string;
+  summary?: string;
+  sha?: string;
+}
+
+export type ActivitySourceConfig =
+  | { source: "github"; filter?: GitHubRepoFilter }
+  | { source: "local_git"; repo_roots: string[]; author_emails: string[] }
+  | { source: "claude"; subscribed_folders: string[] }
+  | { source: "jira" };
+
+export interface ActivitySourceStatus {
+  source: ActivitySourceKind;
+  item_count: number;
+  error?: string;
+}
+
+export interface DailyActivityResponse {
+  date: string;
+  items: ActivityItem[];
+  sources: ActivitySourceStatus[];
+}
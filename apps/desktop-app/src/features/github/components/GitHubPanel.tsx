@@ -19,7 +19,11 @@ import { SidebarContent, SidebarHeader } from "@/components/ui/sidebar";
 import { cn } from "@/lib/utils";
 
 import { useGitHubStore } from "../store/githubStore";
-import type { GitHubActivityItem, GitHubActivityKind } from "../types";
+import type {
+  GitHubActivityItem,
+  GitHubActivityKind,
+  GitHubPullRequestState,
+} from "../types";
 
 const formatTimestamp = (value: string) => {
   const parsed = parseISO(value);
@@ -32,16 +36,34 @@ const kindLabelMap: Record<GitHubActivityKind, string> = {
   pull_request: "PR",
   review: "Review",
   comment: "Comment",
+  merged: "Merged",
+  issue_opened: "Issue",
+  issue_closed: "Closed",
+  released: "Release",
+};
+
+const prStateLabelMap: Record<GitHubPullRequestState, string> = {
+  open: "Open",
+  draft: "Draft",
+  merged: "Merged",
+  closed: "Closed",
 };
 
 const getMetaLine = (item: GitHubActivityItem) => {
-  const parts = [item.summary, formatTimestamp(item.timestamp)].filter(Boolean);
+  const parts = [
+    item.summary,
+    item.pr_state && item.kind !== "merged"
+      ? prStateLabelMap[item.pr_state]
+      : undefined,
+    formatTimestamp(item.timestamp),
+  ].filter(Boolean);
   return parts.join(" · ");
 };
 
 export function GitHubPanel() {
   const status = useGitHubStore((state) => state.status);
   const error = useGitHubStore((state) => state.error);
+  const notices = useGitHubStore((state) => state.notices);
   const login = useGitHubStore((state) => state.login);
   const activities = useGitHubStore((state) => state.activities);
   const selectedDate = useGitHubStore((state) => state.selectedDate);
@@ -73,6 +95,11 @@ export function GitHubPanel() {
         </div>
         {login && <div className="text-xs text-muted-foreground">@{login}</div>}
         {error && <div className="text-xs text-destructive">{error}</div>}
+        {notices.map((notice) => (
+          <div key={notice} className="text-xs text-muted-foreground">
+            {notice}
+          </div>
+        ))}
       </SidebarHeader>
 
       <SidebarContent>
@@ -12,6 +12,8 @@ import type {
 type GitHubStoreState = {
   status: GitHubStatus;
   error: string | null;
+  /** Why some activity may be missing, per host. */
+  notices: string[];
   login: string | null;
   selectedDate: Date;
   activities: GitHubActivityItem[];
@@ -54,25 +56,33 @@ const resolveErrorMessage = (error: unknown, fallback: string): string => {
 const fetchActivity = async (
   date: Date,
   set: (partial: Partial<GitHubStoreState>) => void,
-  get: () => GitHubStoreState
+  get: () => GitHubStoreState,
+  forceRefresh = false
 ) => {
   const dateKey = formatDateKey(date);
   const requestId = Date.now();
   set({
     isLoading: true,
     error: null,
+    notices: [],
     status: "loading",
     activeRequestId: requestId,
   });
 
   try {
-    const response: GitHubActivityResponse = await getGitHubActivity(dateKey);
+    const response: GitHubActivityResponse = await getGitHubActivity(
+      dateKey,
+      forceRefresh
+    );
     if (get().activeRequestId !== requestId) return;
     set({
       status: "connected",
       login: response.login,
       activities: response.items ?? [],
       error: null,
+      notices: (response.hosts ?? []).flatMap((host) =>
+        host.incomplete ? [`${host.host}: ${host.incomplete}`] : []
+      ),
     });
   } catch (error) {
     const message = resolveErrorMessage(
@@ -96,6 +106,7 @@ const fetchActivity = async (
 export const useGitHubStore = create<GitHubStoreState>((set, get) => ({
   status: "idle",
   error: null,
+  notices: [],
   login: null,
   selectedDate: new Date(),
   activities: [],
@@ -109,6 +120,6 @@ export const useGitHubStore = create<GitHubStoreState>((set, get) => ({
   },
 
   refresh: async () => {
-    await fetchActivity(get().selectedDate, set, get);
+    await fetchActivity(get().selectedDate, set, get, true);
   },
 }));
@@ -2,22 +2,78 @@ export type GitHubActivityKind =
   | "commit"
   | "pull_request"
   | "review"
-  | "comment";
+  | "comment"
+  | "merged"
+  | "issue_opened"
+  | "issue_closed"
+  | "released";
+
+export type GitHubPullRequestState = "open" | "draft" | "merged" | "closed";
 
 export interface GitHubActivityItem {
   kind: GitHubActivityKind;
   title: string;
   url: string;
   repo: string;
+  host: string;
   timestamp: string;
   number?: number;
   summary?: string;
+  pr_state?: GitHubPullRequestState;
+  commit?: GitHubCommitDetails;
+}
+
+export interface GitHubCommitDetails {
+  sha: string;
+  body?: string;
+  branch?: string;
+  pull_request_title?: string;
+  /** Unknown for commits touching 300 or more files. */
+  files_changed?: number;
+  additions?: number;
+  deletions?: number;
+  jira_keys: string[];
+}
+
+export type GitHubError =
+  | {
+      kind: "rate_limited";
+      message: string;
+      reset_at?: number;
+      secondary: boolean;
+    }
+  | { kind: "request"; message: string };
+
+export interface GitHubHost {
+  /** Host name, e.g. "github.com" or "github.example.com". */
+  host: string;
+  /** Defaults to the host's standard REST API URL. */
+  api_url: string | null;
+  /** Looked up from the token when empty. */
+  login: string | null;
+}
+
+export interface GitHubHostStatus {
+  host: string;
+  login?: string;
+  error?: GitHubError;
+  /** Why some of this host's activity may be missing. */
+  incomplete?: string;
+}
+
+/**
+ * Glob patterns; ones with a "/" match "owner/repo", others match the owner.
+ */
+export interface GitHubRepoFilter {
+  include: string[];
+  exclude: string[];
 }
 
 export interface GitHubActivityResponse {
   login: string;
   date: string;
   items: GitHubActivityItem[];
+  hosts: GitHubHostStatus[];
 }
 
 export type GitHubStatus =
@@ -26,3 +82,71 @@ export type GitHubStatus =
   | "connected"
   | "disconnected"
   | "error";
+
+export interface GitHubActivityCounts {
+  commits: number;
+  pull_requests_opened: number;
+  pull_requests_merged: number;
+  reviews: number;
+  comments: number;
+  issues_opened: number;
+  issues_closed: number;
+  releases: number;
+}
+
+export interface GitHubRepoActivity {
+  host: string;
+  repo: string;
+  counts: GitHubActivityCounts;
+  items: GitHubActivityItem[];
+}
+
+export interface GitHubDayActivity {
+  date: string;
+  counts: GitHubActivityCounts;
+  repos: GitHubRepoActivity[];
+}
+
+export interface GitHubActivityRangeResponse {
+  login: string;
+  from: string;
+  to: string;
+  totals: GitHubActivityCounts;
+  days: GitHubDayActivity[];
+  hosts: GitHubHostStatus[];
+}
+
+export interface LocalGitRepoStatus {
+  path: string;
+  error?: string;
+}
+
+export interface LocalGitActivityResponse {
+  date: string;
+  items: GitHubActivityItem[];
+  repos: LocalGitRepoStatus[];
+}
+
+export type GitHubCiStatus = "success" | "failure" | "pending" | "none";
+
+export interface GitHubInboxItem {
+  title: string;
+  url: string;
+  repo: string;
+  host: string;
+  number: number;
+  created_at: string;
+  age_hours: number;
+  pr_state?: GitHubPullRequestState;
+  ci_status?: GitHubCiStatus;
+  new_reviews: number;
+  latest_review?: string;
+}
+
+export interface GitHubInboxResponse {
+  login: string;
+  review_requests: GitHubInboxItem[];
+  my_pull_requests: GitHubInboxItem[];
+  assigned_issues: GitHubInboxItem[];
+  hosts: GitHubHostStatus[];
+}
@@ -47,6 +47,7 @@ export function JiraPanel() {
     issues,
     isLoadingIssues,
     hasStoredToken,
+    credentialStatus,
     setBaseUrl,
     setEmail,
     setApiToken,
@@ -133,6 +134,16 @@ export function JiraPanel() {
         </div>
 
         {error && <div className="text-xs text-destructive">{error}</div>}
+        {!error && credentialStatus?.lastSuccessAt && (
+          <div className="text-xs text-muted-foreground">
+            Token last accepted{" "}
+            {formatInKst(
+              new Date(credentialStatus.lastSuccessAt),
+              "MMM d, HH:mm"
+            )}
+            {credentialStatus.email ? ` for ${credentialStatus.email}` : ""}
+          </div>
+        )}
       </div>
 
       <Calendar
@@ -1,23 +1,22 @@
-import type {
-  JiraIssue,
-  JiraIssuesResponse,
-  JiraTestRequest,
-  JiraTestResponse,
-  JiraUserProfile,
-} from "@enttokk/api-types";
+import type { JiraIssue, JiraUserProfile } from "@enttokk/api-types";
 import { create } from "zustand";
 
-import { apiClient } from "@/lib/api-client";
 import {
-  getJiraToken,
-  removeJiraToken,
-  setJiraToken,
-} from "@/lib/secure-store";
+  getJiraCredentialStatus,
+  searchJiraIssues,
+  testJiraConnection,
+} from "@/lib/jira";
+import { removeJiraToken, setJiraToken } from "@/lib/secure-store";
 import { getValue, setValue } from "@/lib/tauri-store";
 
-import type { JiraStatus, JiraStoredState } from "../types";
+import type {
+  JiraCredentialStatus,
+  JiraStatus,
+  JiraStoredState,
+} from "../types";
 
 const STORAGE_KEY = "jiraIntegration";
+const ASSIGNED_ISSUES_JQL = "assignee = currentUser() ORDER BY updated DESC";
 
 type JiraStoreState = {
   status: JiraStatus;
@@ -30,13 +29,16 @@ type JiraStoreState = {
   issues: JiraIssue[];
   isLoadingIssues: boolean;
   hasStoredToken: boolean;
+  credentialStatus: JiraCredentialStatus | null;
 
   setBaseUrl: (value: string) => void;
   setEmail: (value: string) => void;
   setApiToken: (value: string) => void;
 
   loadFromStore: () => Promise<void>;
-  save: () => Promise<void>;
+  /** Resolves to false when the token could not be verified or stored. */
+  save: () => Promise<boolean>;
+  refreshCredentialStatus: () => Promise<void>;
   testConnection: () => Promise<void>;
   fetchIssues: () => Promise<void>;
   disconnect: () => Promise<void>;
@@ -64,19 +66,12 @@ const buildStoredState = (state: JiraStoreState): JiraStoredState => ({
   lastCheckedAt: state.lastCheckedAt ?? undefined,
 });
 
-const hasCredentials = (request: JiraTestRequest) =>
-  Boolean(request.baseUrl && request.email && request.apiToken);
-
-const resolveToken = async (state: JiraStoreState) => {
-  const token = state.apiToken.trim();
-  if (token) return token;
-  try {
-    const stored = await getJiraToken();
-    return stored?.trim() ?? "";
-  } catch {
-    return "";
-  }
-};
+const hasCredentials = (state: JiraStoreState) =>
+  Boolean(
+    normalizeUrl(state.baseUrl) &&
+      state.email.trim() &&
+      (state.apiToken.trim() || state.hasStoredToken)
+  );
 
 export const useJiraStore = create<JiraStoreState>((set, get) => ({
   status: "disconnected",
@@ -89,6 +84,7 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
   issues: [],
   isLoadingIssues: false,
   hasStoredToken: false,
+  credentialStatus: null,
 
   setBaseUrl: (value) => set({ baseUrl: value, error: null }),
   setEmail: (value) => set({ email: value, error: null }),
@@ -97,9 +93,10 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
   loadFromStore: async () => {
     const stored = await getValue<JiraStoredState>(STORAGE_KEY);
     if (!stored) return;
-    let token: string | null = null;
+    // Only whether a token is saved crosses into the webview, never the token
+    let credentialStatus: JiraCredentialStatus | null = null;
     try {
-      token = await getJiraToken();
+      credentialStatus = await getJiraCredentialStatus();
     } catch (error) {
       set({
         error:
@@ -108,6 +105,7 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
             : "Unable to access secure storage",
       });
     }
+    const hasStoredToken = credentialStatus?.hasToken ?? false;
     const normalized = normalizeTimestamps(stored);
     set({
       baseUrl: normalized.baseUrl ?? "",
@@ -115,10 +113,18 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
       apiToken: "",
       profile: normalized.profile ?? null,
       lastCheckedAt: normalized.lastCheckedAt ?? null,
-      hasStoredToken: Boolean(token?.trim()),
-      status: token ? "connected" : "disconnected",
+      hasStoredToken,
+      credentialStatus,
+      status: hasStoredToken ? "connected" : "disconnected",
     });
-    if (token) {
+    if (credentialStatus?.valid === false) {
+      set({
+        status: "error",
+        error: "Jira rejected the saved API token. Enter a new one.",
+      });
+      return;
+    }
+    if (hasStoredToken) {
       await get().fetchIssues();
     }
   },
@@ -129,28 +135,33 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
     const trimmedToken = state.apiToken.trim();
     try {
       if (trimmedToken) {
-        await setJiraToken(trimmedToken);
+        // Checked against the site saved above before it replaces the old token
+        await setJiraToken(trimmedToken, { verify: true });
         set({ apiToken: "", hasStoredToken: true });
+        await get().refreshCredentialStatus();
       }
+      return true;
     } catch (error) {
       set({
         error:
           error instanceof Error
             ? error.message
-            : "Unable to update secure storage",
+            : String(error) || "Unable to update secure storage",
       });
+      return false;
+    }
+  },
+
+  refreshCredentialStatus: async () => {
+    try {
+      set({ credentialStatus: await getJiraCredentialStatus() });
+    } catch {
+      set({ credentialStatus: null });
     }
   },
 
   testConnection: async () => {
-    const state = get();
-    const apiToken = await resolveToken(state);
-    const request: JiraTestRequest = {
-      baseUrl: normalizeUrl(state.baseUrl),
-      email: state.email.trim(),
-      apiToken,
-    };
-    if (!hasCredentials(request)) {
+    if (!hasCredentials(get())) {
       set({
         status: "error",
         error: "Base URL, email, and API token are required.",
@@ -160,20 +171,25 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
 
     set({ status: "connecting", error: null });
     try {
-      const response: JiraTestResponse =
-        await apiClient.jira.testConnection(request);
+      // The Rust client reads the site from settings and the saved token
+      if (!(await get().save())) {
+        set({ status: "error", profile: null, lastCheckedAt: null });
+        return;
+      }
+      const profile = await testJiraConnection();
       set({
         status: "connected",
-        profile: response.profile ?? null,
+        profile,
         lastCheckedAt: new Date().toISOString(),
         error: null,
       });
       await get().save();
+      await get().refreshCredentialStatus();
       await get().fetchIssues();
     } catch (error) {
       set({
         status: "error",
-        error: error instanceof Error ? error.message : "Jira test failed",
+        error: error instanceof Error ? error.message : String(error),
         profile: null,
         lastCheckedAt: null,
         issues: [],
@@ -183,14 +199,7 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
   },
 
   fetchIssues: async () => {
-    const state = get();
-    const apiToken = await resolveToken(state);
-    const request: JiraTestRequest = {
-      baseUrl: normalizeUrl(state.baseUrl),
-      email: state.email.trim(),
-      apiToken,
-    };
-    if (!hasCredentials(request)) {
+    if (!hasCredentials(get())) {
       set({
         status: "error",
         error: "Base URL, email, and API token are required.",
@@ -200,17 +209,16 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
 
     set({ isLoadingIssues: true, error: null });
     try {
-      const response: JiraIssuesResponse =
-        await apiClient.jira.listIssues(request);
+      const issues = await searchJiraIssues(ASSIGNED_ISSUES_JQL);
       set({
         status: "connected",
-        issues: response.issues ?? [],
+        issues,
         error: null,
       });
     } catch (error) {
       set({
         issues: [],
-        error: error instanceof Error ? error.message : "Jira issues failed",
+        error: error instanceof Error ? error.message : String(error),
       });
     } finally {
       set({ isLoadingIssues: false });
@@ -229,6 +237,7 @@ export const useJiraStore = create<JiraStoreState>((set, get) => ({
       issues: [],
       isLoadingIssues: false,
       hasStoredToken: false,
+      credentialStatus: null,
     });
     await setValue(STORAGE_KEY, null);
     try {
@@ -8,3 +8,17 @@ export interface JiraStoredState {
   profile?: JiraUserProfile;
   lastCheckedAt?: string;
 }
+
+/** State of the saved API token. Timestamps are Unix milliseconds. */
+export interface JiraCredentialStatus {
+  hasToken: boolean;
+  /** `null` until checked, or when the site changed since. */
+  valid: boolean | null;
+  accountId: string | null;
+  email: string | null;
+  site: string | null;
+  siteChanged: boolean;
+  updatedAt: number | null;
+  lastUsedAt: number | null;
+  lastSuccessAt: number | null;
+}
@@ -0,0 +1,138 @@
+import * as React from "react";
+import { Label } from "@/components/ui/label";
+import { Switch } from "@/components/ui/switch";
+import { Textarea } from "@/components/ui/textarea";
+import type { ClaudeRedactionSettings } from "@/features/claude-activity/types";
+import {
+  getClaudeDataDirs,
+  getClaudeRedactionSettings,
+  setClaudeDataDirs,
+  setClaudeRedactionSettings,
+} from "@/lib/claude";
+
+const toLines = (value: string) =>
+  value
+    .split("\n")
+    .map((line) => line.trim())
+    .filter(Boolean);
+
+const errorMessage = (error: unknown) =>
+  error instanceof Error ? error.message : String(error);
+
+export function ClaudeSettingsSection({ open }: { open: boolean }) {
+  const [dirsInput, setDirsInput] = React.useState("");
+  const [redaction, setRedaction] =
+    React.useState<ClaudeRedactionSettings | null>(null);
+  const [patternsInput, setPatternsInput] = React.useState("");
+  const [error, setError] = React.useState<string | null>(null);
+
+  React.useEffect(() => {
+    if (!open) return;
+    Promise.all([getClaudeDataDirs(), getClaudeRedactionSettings()])
+      .then(([dirs, settings]) => {
+        setDirsInput(dirs.join("\n"));
+        setRedaction(settings);
+        setPatternsInput(settings.custom_patterns.join("\n"));
+        setError(null);
+      })
+      .catch((error) => setError(errorMessage(error)));
+  }, [open]);
+
+  const handleDirsBlur = async () => {
+    try {
+      const dirs = await setClaudeDataDirs(toLines(dirsInput));
+      setDirsInput(dirs.join("\n"));
+      setError(null);
+    } catch (error) {
+      setError(errorMessage(error));
+    }
+  };
+
+  const saveRedaction = async (settings: ClaudeRedactionSettings) => {
+    try {
+      const saved = await setClaudeRedactionSettings(settings);
+      setRedaction(saved);
+      setPatternsInput(saved.custom_patterns.join("\n"));
+      setError(null);
+    } catch (error) {
+      setError(errorMessage(error));
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      <Label className="text-sm font-medium">Claude</Label>
+      <div className="space-y-4">
+        <div className="space-y-2">
+          <Label
+            htmlFor="claude-data-dirs"
+            className="text-xs text-muted-foreground"
+          >
+            Data directories, one per line (empty uses ~/.claude)
+          </Label>
+          <Textarea
+            id="claude-data-dirs"
+            value={dirsInput}
+            onChange={(e) => setDirsInput(e.target.value)}
+            onBlur={handleDirsBlur}
+            placeholder="~/.claude"
+            className="min-h-[60px] font-mono text-sm"
+          />
+        </div>
+
+        {redaction && (
+          <div className="space-y-3">
+            <div className="flex items-center justify-between">
+              <Label htmlFor="claude-redaction" className="text-xs">
+                Redact secrets in Claude content
+              </Label>
+              <Switch
+                id="claude-redaction"
+                checked={redaction.enabled}
+                onCheckedChange={(enabled) =>
+                  saveRedaction({ ...redaction, enabled })
+                }
+              />
+            </div>
+            <div className="flex items-center justify-between">
+              <Label htmlFor="claude-redaction-entropy" className="text-xs">
+                Also redact random-looking tokens
+              </Label>
+              <Switch
+                id="claude-redaction-entropy"
+                checked={redaction.high_entropy}
+                disabled={!redaction.enabled}
+                onCheckedChange={(high_entropy) =>
+                  saveRedaction({ ...redaction, high_entropy })
+                }
+              />
+            </div>
+            <div className="space-y-2">
+              <Label
+                htmlFor="claude-redaction-patterns"
+                className="text-xs text-muted-foreground"
+              >
+                Extra patterns to redact (regular expressions, one per line)
+              </Label>
+              <Textarea
+                id="claude-redaction-patterns"
+                value={patternsInput}
+                onChange={(e) => setPatternsInput(e.target.value)}
+                onBlur={() =>
+                  saveRedaction({
+                    ...redaction,
+                    custom_patterns: toLines(patternsInput),
+                  })
+                }
+                disabled={!redaction.enabled}
+                className="min-h-[60px] font-mono text-sm"
+              />
+            </div>
+          </div>
+        )}
+
+        {error && <p className="text-xs text-destructive">{error}</p>}
+      </div>
+    </div>
+  );
+}
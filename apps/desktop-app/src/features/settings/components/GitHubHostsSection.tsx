@@ -0,0 +1,115 @@
+import { Plus, Trash2 } from "lucide-react";
+import * as React from "react";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import type { GitHubHost } from "@/features/github/types";
+import { getGitHubHosts, setGitHubHosts } from "@/lib/github";
+
+const emptyHost = (): GitHubHost => ({ host: "", api_url: null, login: null });
+
+export function GitHubHostsSection({ open }: { open: boolean }) {
+  const [hosts, setHosts] = React.useState<GitHubHost[]>([]);
+  const [isSaving, setIsSaving] = React.useState(false);
+  const [error, setError] = React.useState<string | null>(null);
+
+  React.useEffect(() => {
+    if (!open) return;
+    getGitHubHosts()
+      .then((hosts) => {
+        setHosts(hosts);
+        setError(null);
+      })
+      .catch((error) => setError(String(error)));
+  }, [open]);
+
+  const updateHost = (index: number, patch: Partial<GitHubHost>) => {
+    setHosts((hosts) =>
+      hosts.map((host, i) => (i === index ? { ...host, ...patch } : host))
+    );
+  };
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    try {
+      const saved = await setGitHubHosts(
+        hosts.filter((host) => host.host.trim())
+      );
+      setHosts(saved);
+      setError(null);
+    } catch (error) {
+      setError(error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      <Label className="text-sm font-medium">GitHub Hosts</Label>
+      <div className="space-y-2">
+        {hosts.map((host, index) => (
+          <div key={index} className="flex items-center gap-2">
+            <Input
+              value={host.host}
+              onChange={(e) => updateHost(index, { host: e.target.value })}
+              placeholder="github.com"
+              className="h-8 text-xs"
+            />
+            <Input
+              value={host.api_url ?? ""}
+              onChange={(e) =>
+                updateHost(index, { api_url: e.target.value || null })
+              }
+              placeholder="API URL (optional)"
+              className="h-8 text-xs"
+            />
+            <Input
+              value={host.login ?? ""}
+              onChange={(e) =>
+                updateHost(index, { login: e.target.value || null })
+              }
+              placeholder="Login (optional)"
+              className="h-8 w-28 text-xs"
+            />
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() =>
+                setHosts((hosts) => hosts.filter((_, i) => i !== index))
+              }
+              title="Remove host"
+              className="h-8"
+            >
+              <Trash2 className="size-3" />
+            </Button>
+          </div>
+        ))}
+        <div className="flex items-center gap-2">
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={() => setHosts((hosts) => [...hosts, emptyHost()])}
+            className="h-8"
+          >
+            <Plus className="size-3" />
+            <span className="ml-1.5">Add host</span>
+          </Button>
+          <Button
+            size="sm"
+            onClick={handleSave}
+            disabled={isSaving}
+            className="h-8"
+          >
+            Save
+          </Button>
+        </div>
+        <p className="text-xs text-muted-foreground">
+          Tokens for each host are set in the GitHub panel. No hosts means
+          github.com only.
+        </p>
+        {error && <p className="text-xs text-destructive">{error}</p>}
+      </div>
+    </div>
+  );
+}
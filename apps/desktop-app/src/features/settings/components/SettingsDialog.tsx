@@ -25,9 +25,12 @@ import { Separator } from "@/components/ui/separator";
 import { Textarea } from "@/components/ui/textarea";
 import { DEFAULT_DAILY_NOTES_SETTINGS } from "@/features/daily-notes/types";
 import { useBackend } from "@/hooks/useBackend";
+import { getSecretBackend, migrateSecrets } from "@/lib/secure-store";
 
 import { useSettingsStore } from "../store/settingsStore";
-import type { Theme } from "../types";
+import type { SecretBackend, Theme } from "../types";
+import { ClaudeSettingsSection } from "./ClaudeSettingsSection";
+import { GitHubHostsSection } from "./GitHubHostsSection";
 
 interface SettingsDialogProps {
   open: boolean;
@@ -40,6 +43,30 @@ const themeOptions: { value: Theme; label: string; icon: typeof Sun }[] = [
   { value: "system", label: "System", icon: Monitor },
 ];
 
+const secretBackendOptions: {
+  value: SecretBackend;
+  label: string;
+  description: string;
+}[] = [
+  {
+    value: "keyring",
+    label: "OS keyring",
+    description: "Kept by the operating system's credential store.",
+  },
+  {
+    value: "stronghold",
+    label: "Stronghold",
+    description:
+      "Unlocked by a password file kept beside it, so it does not protect against local file access.",
+  },
+  {
+    value: "local_file",
+    label: "Local file",
+    description:
+      "Sealed with a key file kept beside it, so it does not protect against local file access.",
+  },
+];
+
 function isValidFolderName(name: string): boolean {
   if (!name.trim()) return false;
   // Prevent path traversal: no slashes, backslashes, or parent directory references
@@ -71,6 +98,40 @@ export function SettingsDialog({ open, onOpenChange }: SettingsDialogProps) {
     settings.dailyNotes?.template ?? DEFAULT_DAILY_NOTES_SETTINGS.template
   );
 
+  const [secretBackend, setSecretBackend] =
+    React.useState<SecretBackend | null>(null);
+  const [isMigrating, setIsMigrating] = React.useState(false);
+  const [secretMessage, setSecretMessage] = React.useState<string | null>(
+    null
+  );
+
+  React.useEffect(() => {
+    if (!open) return;
+    getSecretBackend()
+      .then(setSecretBackend)
+      .catch((error) => setSecretMessage(String(error)));
+  }, [open]);
+
+  const handleSecretBackendChange = async (value: string) => {
+    if (!secretBackend || value === secretBackend) return;
+    setIsMigrating(true);
+    setSecretMessage(null);
+    try {
+      const result = await migrateSecrets(
+        secretBackend,
+        value as SecretBackend
+      );
+      setSecretBackend(result.backend);
+      setSecretMessage(
+        `Moved ${result.migrated.length} secret${result.migrated.length === 1 ? "" : "s"}`
+      );
+    } catch (error) {
+      setSecretMessage(error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsMigrating(false);
+    }
+  };
+
   const handleCheckHealth = async () => {
     setIsChecking(true);
     await checkHealth();
@@ -221,6 +282,56 @@ export function SettingsDialog({ open, onOpenChange }: SettingsDialogProps) {
 
             <Separator />
 
+            <div className="space-y-3">
+              <Label className="text-sm font-medium">Secret Storage</Label>
+              <RadioGroup
+                value={secretBackend ?? ""}
+                onValueChange={handleSecretBackendChange}
+                disabled={!secretBackend || isMigrating}
+                className="grid grid-cols-3 gap-2"
+              >
+                {secretBackendOptions.map(({ value, label }) => (
+                  <Label
+                    key={value}
+                    htmlFor={`secret-backend-${value}`}
+                    className="flex cursor-pointer items-center justify-center rounded-lg border border-input p-3 transition-colors hover:bg-accent has-[:checked]:border-primary has-[:checked]:bg-accent"
+                  >
+                    <RadioGroupItem
+                      id={`secret-backend-${value}`}
+                      value={value}
+                      className="sr-only"
+                    />
+                    <span className="text-xs">{label}</span>
+                  </Label>
+                ))}
+              </RadioGroup>
+              {secretBackend && (
+                <p className="text-xs text-muted-foreground">
+                  {
+                    secretBackendOptions.find(
+                      ({ value }) => value === secretBackend
+                    )?.description
+                  }
+                </p>
+              )}
+              <p className="text-xs text-muted-foreground">
+                {isMigrating
+                  ? "Moving secrets..."
+                  : (secretMessage ??
+                    "Tokens are moved to the selected storage when you switch.")}
+              </p>
+            </div>
+
+            <Separator />
+
+            <GitHubHostsSection open={open} />
+
+            <Separator />
+
+            <ClaudeSettingsSection open={open} />
+
+            <Separator />
+
             <div className="space-y-3">
               <Label className="text-sm font-medium">Daily Notes</Label>
               <div className="space-y-4">
@@ -14,3 +14,36 @@ export const DEFAULT_SETTINGS: Settings = {
   theme: "system",
   dailyNotes: DEFAULT_DAILY_NOTES_SETTINGS,
 };
+
+/** Secrets the Rust credential vault accepts. GitHub tokens for other hosts
+ * use "github-token@<host>". */
+export type SecretKey =
+  | "jira-api-token"
+  | "github-token"
+  | `github-token@${string}`
+  | "google-oauth-refresh-token";
+
+export type SecretBackend = "keyring" | "local_file" | "stronghold";
+
+/** Timestamps are Unix milliseconds. */
+export interface SecretInfo {
+  key: SecretKey;
+  created_at: number | null;
+  updated_at: number | null;
+  last_used_at: number | null;
+  last_success_at: number | null;
+  /** `null` until the service has accepted or rejected the secret. */
+  valid: boolean | null;
+  account: SecretAccount | null;
+}
+
+export interface SecretAccount {
+  id: string | null;
+  email: string | null;
+  site: string | null;
+}
+
+export interface SecretMigrationResult {
+  backend: SecretBackend;
+  migrated: SecretKey[];
+}
@@ -0,0 +1,16 @@
+import { invoke } from "@tauri-apps/api/core";
+
+import type {
+  ActivitySourceConfig,
+  DailyActivityResponse,
+} from "@/features/daily-summary/types";
+
+export async function getDailyActivity(
+  date: string,
+  sources: ActivitySourceConfig[]
+): Promise<DailyActivityResponse> {
+  return invoke<DailyActivityResponse>("get_daily_activity", {
+    date,
+    sources,
+  });
+}
@@ -7,10 +7,6 @@ import type {
   GoogleCalendarEventsResponse,
   GoogleCalendarTokenResponse,
   HealthResponse,
-  JiraIssuesRequest,
-  JiraIssuesResponse,
-  JiraTestRequest,
-  JiraTestResponse,
 } from "@enttokk/api-types";
 
 const BACKEND_PORT = 31337;
@@ -279,40 +275,4 @@ export const apiClient = {
       return { status: response.status, data };
     },
   },
-
-  // Jira integration methods
-  jira: {
-    async testConnection(params: JiraTestRequest): Promise<JiraTestResponse> {
-      const response = await fetchWithTimeout(
-        `${BACKEND_URL}/integrations/jira/test`,
-        {
-          method: "POST",
-          headers: { "Content-Type": "application/json" },
-          body: JSON.stringify(params),
-          timeout: 15000,
-        }
-      );
-      const data = await response.json().catch(() => ({}));
-      if (!response.ok) {
-        throw new Error(data.error ?? `Jira test failed: ${response.status}`);
-      }
-      return data;
-    },
-    async listIssues(params: JiraIssuesRequest): Promise<JiraIssuesResponse> {
-      const response = await fetchWithTimeout(
-        `${BACKEND_URL}/integrations/jira/issues`,
-        {
-          method: "POST",
-          headers: { "Content-Type": "application/json" },
-          body: JSON.stringify(params),
-          timeout: 15000,
-        }
-      );
-      const data = await response.json().catch(() => ({}));
-      if (!response.ok) {
-        throw new Error(data.error ?? `Jira issues failed: ${response.status}`);
-      }
-      return data;
-    },
-  },
 };
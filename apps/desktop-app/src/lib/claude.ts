@@ -1,6 +1,9 @@
 import { invoke } from "@tauri-apps/api/core";
 
-import type { ClaudeActivityResponse } from "@/features/claude-activity/types";
+import type {
+  ClaudeActivityResponse,
+  ClaudeRedactionSettings,
+} from "@/features/claude-activity/types";
 
 export async function listClaudeProjects(): Promise<string[]> {
   return invoke<string[]>("list_claude_projects");
@@ -27,3 +30,23 @@ export async function getClaudeActivityDates(
     month,
   });
 }
+
+export async function getClaudeDataDirs(): Promise<string[]> {
+  return invoke<string[]>("get_claude_data_dirs");
+}
+
+export async function setClaudeDataDirs(roots: string[]): Promise<string[]> {
+  return invoke<string[]>("set_claude_data_dirs", { roots });
+}
+
+export async function getClaudeRedactionSettings(): Promise<ClaudeRedactionSettings> {
+  return invoke<ClaudeRedactionSettings>("get_claude_redaction_settings");
+}
+
+export async function setClaudeRedactionSettings(
+  settings: ClaudeRedactionSettings
+): Promise<ClaudeRedactionSettings> {
+  return invoke<ClaudeRedactionSettings>("set_claude_redaction_settings", {
+    settings,
+  });
+}
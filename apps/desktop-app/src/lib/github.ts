@@ -1,9 +1,60 @@
 import { invoke } from "@tauri-apps/api/core";
 
-import type { GitHubActivityResponse } from "@/features/github/types";
+import type {
+  GitHubActivityRangeResponse,
+  GitHubActivityResponse,
+  GitHubHost,
+  GitHubInboxResponse,
+  GitHubRepoFilter,
+  LocalGitActivityResponse,
+} from "@/features/github/types";
 
 export async function getGitHubActivity(
-  date: string
+  date: string,
+  forceRefresh = false,
+  filter?: GitHubRepoFilter
 ): Promise<GitHubActivityResponse> {
-  return invoke<GitHubActivityResponse>("get_github_activity", { date });
+  return invoke<GitHubActivityResponse>("get_github_activity", {
+    date,
+    forceRefresh,
+    filter,
+  });
+}
+
+export async function getGitHubActivityRange(
+  from: string,
+  to: string,
+  filter?: GitHubRepoFilter
+): Promise<GitHubActivityRangeResponse> {
+  return invoke<GitHubActivityRangeResponse>("get_github_activity_range", {
+    from,
+    to,
+    filter,
+  });
+}
+
+export async function getGitHubInbox(): Promise<GitHubInboxResponse> {
+  return invoke<GitHubInboxResponse>("get_github_inbox");
+}
+
+export async function getLocalGitActivity(
+  date: string,
+  repoRoots: string[],
+  authorEmails: string[] = []
+): Promise<LocalGitActivityResponse> {
+  return invoke<LocalGitActivityResponse>("get_local_git_activity", {
+    date,
+    repoRoots,
+    authorEmails,
+  });
+}
+
+export async function getGitHubHosts(): Promise<GitHubHost[]> {
+  return invoke<GitHubHost[]>("get_github_hosts");
+}
+
+export async function setGitHubHosts(
+  hosts: GitHubHost[]
+): Promise<GitHubHost[]> {
+  return invoke<GitHubHost[]>("set_github_hosts", { hosts });
 }
@@ -0,0 +1,26 @@
+import type { JiraIssue, JiraUserProfile } from "@enttokk/api-types";
+import { invoke } from "@tauri-apps/api/core";
+
+import type { JiraCredentialStatus } from "@/features/jira/types";
+
+export async function testJiraConnection(): Promise<JiraUserProfile> {
+  return invoke<JiraUserProfile>("jira_test_connection");
+}
+
+export async function searchJiraIssues(
+  jql: string,
+  maxResults?: number
+): Promise<JiraIssue[]> {
+  return invoke<JiraIssue[]>("jira_search_issues", {
+    jql,
+    maxResults: maxResults ?? null,
+  });
+}
+
+export async function getJiraIssue(key: string): Promise<JiraIssue> {
+  return invoke<JiraIssue>("jira_get_issue", { key });
+}
+
+export async function getJiraCredentialStatus(): Promise<JiraCredentialStatus> {
+  return invoke<JiraCredentialStatus>("get_jira_credential_status");
+}
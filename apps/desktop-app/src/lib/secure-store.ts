@@ -1,14 +1,64 @@
 import { invoke } from "@tauri-apps/api/core";
 
-export async function getJiraToken(): Promise<string | null> {
-  const token = await invoke<string | null>("get_jira_token");
-  return token ?? null;
+import type {
+  SecretBackend,
+  SecretInfo,
+  SecretKey,
+  SecretMigrationResult,
+} from "@/features/settings/types";
+
+export async function setSecret(
+  serviceKey: SecretKey,
+  value: string
+): Promise<void> {
+  await invoke("set_secret", { serviceKey, value });
 }
 
-export async function setJiraToken(token: string): Promise<void> {
-  await invoke("set_jira_token", { token });
+export async function deleteSecret(serviceKey: SecretKey): Promise<void> {
+  await invoke("delete_secret", { serviceKey });
+}
+
+export async function listSecretKeys(): Promise<SecretInfo[]> {
+  return invoke<SecretInfo[]>("list_secret_keys");
+}
+
+export async function getSecretBackend(): Promise<SecretBackend> {
+  return invoke<SecretBackend>("get_secret_backend");
+}
+
+export async function migrateSecrets(
+  from: SecretBackend,
+  to: SecretBackend
+): Promise<SecretMigrationResult> {
+  return invoke<SecretMigrationResult>("migrate_secrets", { from, to });
+}
+
+/** With `verify`, the token is checked against the saved Jira site first. */
+export async function setJiraToken(
+  token: string,
+  options?: { verify?: boolean }
+): Promise<void> {
+  await invoke("set_jira_token", { token, verify: options?.verify ?? null });
 }
 
 export async function removeJiraToken(): Promise<void> {
   await invoke("remove_jira_token");
 }
+
+export async function getGitHubToken(host?: string): Promise<string | null> {
+  const token = await invoke<string | null>("get_github_token", {
+    host: host ?? null,
+  });
+  return token ?? null;
+}
+
+export async function setGitHubToken(
+  token: string,
+  host?: string
+): Promise<void> {
+  await invoke("set_github_token", { token, host: host ?? null });
+}
+
+export async function removeGitHubToken(host?: string): Promise<void> {
+  await invoke("remove_github_token", { host: host ?? null });
+}
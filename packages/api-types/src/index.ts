@@ -37,10 +37,6 @@ export type {
 
 export type {
   JiraIssue,
-  JiraIssuesRequest,
-  JiraIssuesResponse,
-  JiraTestRequest,
-  JiraTestResponse,
   JiraUserProfile,
 } from "./jira";
 
@@ -1,9 +1,3 @@
-export interface JiraTestRequest {
-  baseUrl: string;
-  email: string;
-  apiToken: string;
-}
-
 export interface JiraUserProfile {
   displayName: string;
   emailAddress?: string;
@@ -11,24 +5,13 @@ export interface JiraUserProfile {
   avatarUrls?: Record<string, string>;
 }
 
-export interface JiraTestResponse {
-  profile: JiraUserProfile;
-}
-
-export interface JiraIssuesRequest {
-  baseUrl: string;
-  email: string;
-  apiToken: string;
-}
-
 export interface JiraIssue {
   key: string;
   summary: string;
   status: string;
   updated?: string;
   assignee?: string;
-}
-
-export interface JiraIssuesResponse {
-  issues: JiraIssue[];
+  issueType?: string;
+  priority?: string;
+  url?: string;
 }